@@ -0,0 +1,557 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+// arbitrary-precision integer
+// magnitude is stored as little-endian u32 limbs without leading zeros,
+//   so every number has exactly one representation and zero is never negative //;
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+	neg: bool,
+	mag: Vec<u32>,
+}
+
+fn trim(mag: &mut Vec<u32>) {
+	while let Some(&0) = mag.last() {
+		mag.pop();
+	}
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+	if a.len() != b.len() {
+		return a.len().cmp(&b.len());
+	}
+
+	for k in (0..a.len()).rev() {
+		if a[k] != b[k] {
+			return a[k].cmp(&b[k]);
+		}
+	}
+
+	Ordering::Equal
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+	let mut r: Vec<u32> = Vec::with_capacity(a.len().max(b.len()) + 1);
+	let mut carry: u64 = 0;
+
+	for k in 0..a.len().max(b.len()) {
+		let s: u64 = *a.get(k).unwrap_or(&0) as u64 + *b.get(k).unwrap_or(&0) as u64 + carry;
+		r.push(s as u32);
+		carry = s >> 32;
+	}
+
+	if carry > 0 {
+		r.push(carry as u32);
+	}
+
+	r
+}
+
+// a should be not less than b //;
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+	let mut r: Vec<u32> = Vec::with_capacity(a.len());
+	let mut borrow: i64 = 0;
+
+	for (k, &x) in a.iter().enumerate() {
+		let mut s: i64 = x as i64 - *b.get(k).unwrap_or(&0) as i64 - borrow;
+		borrow = 0;
+		if s < 0 {
+			s += 1 << 32;
+			borrow = 1;
+		}
+
+		r.push(s as u32);
+	}
+
+	trim(&mut r);
+	r
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+	if a.is_empty() || b.is_empty() {
+		return Vec::new();
+	}
+
+	let mut r: Vec<u32> = vec![0; a.len() + b.len()];
+	for i in 0..a.len() {
+		let mut carry: u64 = 0;
+		for j in 0..b.len() {
+			let t: u64 = a[i] as u64 * b[j] as u64 + r[i+j] as u64 + carry;
+			r[i+j] = t as u32;
+			carry = t >> 32;
+		}
+
+		r[i+b.len()] = carry as u32;
+	}
+
+	trim(&mut r);
+	r
+}
+
+fn divrem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+	let mut q: Vec<u32> = vec![0; a.len()];
+	let mut rem: u64 = 0;
+
+	for k in (0..a.len()).rev() {
+		let cur: u64 = (rem << 32) | a[k] as u64;
+		q[k] = (cur / d as u64) as u32;
+		rem = cur % d as u64;
+	}
+
+	trim(&mut q);
+	(q, rem as u32)
+}
+
+// two's complement negation in place, !a + 1 //;
+fn negate_limbs(a: &mut [u32]) {
+	let mut carry: bool = true;
+	for l in a.iter_mut() {
+		let (v, c) = (!*l).overflowing_add(carry as u32);
+		*l = v;
+		carry = c;
+	}
+}
+
+fn bit_len(a: &[u32]) -> usize {
+	match a.last() {
+		Some(top) => (a.len() - 1) * 32 + (32 - top.leading_zeros() as usize),
+		None => 0,
+	}
+}
+
+fn shl_mag(a: &[u32], bits: usize) -> Vec<u32> {
+	if a.is_empty() {
+		return Vec::new();
+	}
+
+	let (limbs, rest) = (bits / 32, bits % 32);
+	let mut r: Vec<u32> = vec![0; limbs];
+	let mut carry: u32 = 0;
+
+	for &x in a.iter() {
+		if rest == 0 {
+			r.push(x);
+		} else {
+			r.push((x << rest) | carry);
+			carry = x >> (32 - rest);
+		}
+	}
+
+	if carry > 0 {
+		r.push(carry);
+	}
+
+	r
+}
+
+fn shr_mag(a: &[u32], bits: usize) -> Vec<u32> {
+	let (limbs, rest) = (bits / 32, bits % 32);
+	if limbs >= a.len() {
+		return Vec::new();
+	}
+
+	let mut r: Vec<u32> = Vec::with_capacity(a.len() - limbs);
+	for k in limbs..a.len() {
+		if rest == 0 {
+			r.push(a[k]);
+		} else {
+			let hi: u32 = if k + 1 < a.len() { a[k+1] << (32 - rest) } else { 0 };
+			r.push((a[k] >> rest) | hi);
+		}
+	}
+
+	trim(&mut r);
+	r
+}
+
+// long division by 32-bit words (Knuth's algorithm D), b should be non-zero //;
+fn divrem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+	if cmp_mag(a, b) == Ordering::Less {
+		return (Vec::new(), a.to_vec());
+	}
+
+	if b.len() == 1 {
+		let (q, r) = divrem_small(a, b[0]);
+		let mut r: Vec<u32> = vec![r];
+		trim(&mut r);
+		return (q, r);
+	}
+
+	// top word of divisor should have its high bit set, so guessed digit
+	//   of quotient is at most 2 more than the real one //;
+	let shift: usize = b[b.len()-1].leading_zeros() as usize;
+	let b: Vec<u32> = shl_mag(b, shift);
+	let mut a: Vec<u32> = {
+		let len: usize = a.len() + 1;
+		let mut a: Vec<u32> = shl_mag(a, shift);
+		a.resize(len, 0);
+		a
+	};
+
+	let n: usize = b.len();
+	let m: usize = a.len() - n;
+	let base: u64 = 1 << 32;
+	let mut q: Vec<u32> = vec![0; m];
+
+	for j in (0..m).rev() {
+		let num: u64 = ((a[j+n] as u64) << 32) | a[j+n-1] as u64;
+		let mut qhat: u64 = num / b[n-1] as u64;
+		let mut rhat: u64 = num % b[n-1] as u64;
+
+		while qhat >= base || qhat * b[n-2] as u64 > ((rhat << 32) | a[j+n-2] as u64) {
+			qhat -= 1;
+			rhat += b[n-1] as u64;
+			if rhat >= base {
+				break
+			}
+		}
+
+		let mut borrow: i64 = 0;
+		let mut carry: u64 = 0;
+		for k in 0..n {
+			let p: u64 = qhat * b[k] as u64 + carry;
+			carry = p >> 32;
+			let t: i64 = a[k+j] as i64 - borrow - (p & 0xffff_ffff) as i64;
+			a[k+j] = t as u32;
+			borrow = if t < 0 { 1 } else { 0 };
+		}
+
+		let t: i64 = a[j+n] as i64 - borrow - carry as i64;
+		a[j+n] = t as u32;
+
+		// guess was 1 too big, so divisor is added back //;
+		if t < 0 {
+			qhat -= 1;
+			let mut c: u64 = 0;
+			for k in 0..n {
+				let s: u64 = a[k+j] as u64 + b[k] as u64 + c;
+				a[k+j] = s as u32;
+				c = s >> 32;
+			}
+
+			a[j+n] = a[j+n].wrapping_add(c as u32);
+		}
+
+		q[j] = qhat as u32;
+	}
+
+	a.truncate(n);
+	trim(&mut a);
+	trim(&mut q);
+	(q, shr_mag(&a, shift))
+}
+
+impl BigInt {
+	fn from_mag(neg: bool, mut mag: Vec<u32>) -> BigInt {
+		trim(&mut mag);
+		BigInt {
+			neg: neg && !mag.is_empty(),
+			mag,
+		}
+	}
+
+	pub fn zero() -> BigInt {
+		BigInt::from_mag(false, Vec::new())
+	}
+
+	pub fn from_u128(mut d: u128) -> BigInt {
+		let mut mag: Vec<u32> = Vec::new();
+		while d > 0 {
+			mag.push(d as u32);
+			d >>= 32;
+		}
+
+		BigInt::from_mag(false, mag)
+	}
+
+	pub fn from_i128(d: i128) -> BigInt {
+		let r: BigInt = BigInt::from_u128(d.unsigned_abs());
+		if d < 0 { r.neg() } else { r }
+	}
+
+	// none if number is negative or doesn't fit //;
+	pub fn to_u128(&self) -> Option<u128> {
+		if self.neg || self.mag.len() > 4 {
+			return None;
+		}
+
+		let mut r: u128 = 0;
+		for &x in self.mag.iter().rev() {
+			r = (r << 32) | x as u128;
+		}
+
+		Some(r)
+	}
+
+	// lowest 128 bits of two's complement representation //;
+	pub fn low_u128(&self) -> u128 {
+		let mut r: u128 = 0;
+		for &x in self.mag.iter().take(4).rev() {
+			r = (r << 32) | x as u128;
+		}
+
+		if self.neg { r.wrapping_neg() } else { r }
+	}
+
+	// count of bits of magnitude //;
+	pub fn bits(&self) -> usize {
+		bit_len(&self.mag)
+	}
+
+	pub fn bit(&self, k: usize) -> bool {
+		self.mag.get(k / 32).is_some_and(|x| (x >> (k % 32)) & 1 == 1)
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.mag.is_empty()
+	}
+
+	pub fn is_neg(&self) -> bool {
+		self.neg
+	}
+
+	pub fn neg(&self) -> BigInt {
+		BigInt::from_mag(!self.neg, self.mag.clone())
+	}
+
+	pub fn abs(&self) -> BigInt {
+		BigInt::from_mag(false, self.mag.clone())
+	}
+
+	pub fn signum(&self) -> BigInt {
+		if self.is_zero() {
+			return BigInt::zero();
+		}
+
+		BigInt::from_mag(self.neg, vec![1])
+	}
+
+	pub fn add(&self, o: &BigInt) -> BigInt {
+		if self.neg == o.neg {
+			return BigInt::from_mag(self.neg, add_mag(&self.mag, &o.mag));
+		}
+
+		match cmp_mag(&self.mag, &o.mag) {
+			Ordering::Less => BigInt::from_mag(o.neg, sub_mag(&o.mag, &self.mag)),
+			_ => BigInt::from_mag(self.neg, sub_mag(&self.mag, &o.mag)),
+		}
+	}
+
+	pub fn sub(&self, o: &BigInt) -> BigInt {
+		self.add(&o.neg())
+	}
+
+	pub fn mul(&self, o: &BigInt) -> BigInt {
+		BigInt::from_mag(self.neg != o.neg, mul_mag(&self.mag, &o.mag))
+	}
+
+	// division rounding toward zero, remainder has sign of dividend
+	//   none on division by zero //;
+	pub fn divrem(&self, o: &BigInt) -> Option<(BigInt, BigInt)> {
+		if o.is_zero() {
+			return None;
+		}
+
+		let (q, r) = divrem_mag(&self.mag, &o.mag);
+		Some((BigInt::from_mag(self.neg != o.neg, q), BigInt::from_mag(self.neg, r)))
+	}
+
+	// division rounding toward negative infinity, remainder has sign of divisor //;
+	pub fn divmod(&self, o: &BigInt) -> Option<(BigInt, BigInt)> {
+		let (q, r) = self.divrem(o)?;
+
+		if !r.is_zero() && r.neg != o.neg {
+			return Some((q.sub(&BigInt::from_u128(1)), r.add(o)));
+		}
+
+		Some((q, r))
+	}
+
+	pub fn pow(&self, mut exp: u128) -> BigInt {
+		let mut base: BigInt = self.clone();
+		let mut r: BigInt = BigInt::from_u128(1);
+
+		while exp > 0 {
+			if exp & 1 == 1 {
+				r = r.mul(&base);
+			}
+
+			exp >>= 1;
+			if exp > 0 {
+				base = base.mul(&base);
+			}
+		}
+
+		r
+	}
+
+	pub fn gcd(&self, o: &BigInt) -> BigInt {
+		let (mut a, mut b) = (self.abs(), o.abs());
+		while !b.is_zero() {
+			let r: BigInt = a.divrem(&b).unwrap().1;
+			a = b;
+			b = r;
+		}
+
+		a
+	}
+
+	// bit operations treat numbers as two's complement with endless sign bits,
+	//   so (~ x) is (- -1 x) and negative numbers can be masked //;
+	pub fn bit_and(&self, o: &BigInt) -> BigInt {
+		self.bitwise(o, |a, b| a & b)
+	}
+
+	pub fn bit_or(&self, o: &BigInt) -> BigInt {
+		self.bitwise(o, |a, b| a | b)
+	}
+
+	pub fn bit_xor(&self, o: &BigInt) -> BigInt {
+		self.bitwise(o, |a, b| a ^ b)
+	}
+
+	pub fn bit_not(&self) -> BigInt {
+		self.neg().sub(&BigInt::from_u128(1))
+	}
+
+	fn bitwise(&self, o: &BigInt, f: impl Fn(u32, u32) -> u32) -> BigInt {
+		// one more limb keeps sign bit of both numbers //;
+		let len: usize = self.mag.len().max(o.mag.len()) + 1;
+		let a: Vec<u32> = self.twos(len);
+		let b: Vec<u32> = o.twos(len);
+		let mut r: Vec<u32> = a.iter().zip(b.iter()).map(|(x, y)| f(*x, *y)).collect();
+
+		let neg: bool = r[len-1] >> 31 == 1;
+		if neg {
+			negate_limbs(&mut r);
+		}
+
+		BigInt::from_mag(neg, r)
+	}
+
+	// len limbs of two's complement form //;
+	fn twos(&self, len: usize) -> Vec<u32> {
+		let mut r: Vec<u32> = self.mag.clone();
+		r.resize(len, 0);
+		if self.neg {
+			negate_limbs(&mut r);
+		}
+
+		r
+	}
+
+	pub fn shl(&self, bits: usize) -> BigInt {
+		BigInt::from_mag(self.neg, shl_mag(&self.mag, bits))
+	}
+
+	// rounds toward negative infinity, like arithmetic shift //;
+	pub fn shr(&self, bits: usize) -> BigInt {
+		let r: BigInt = BigInt::from_mag(self.neg, shr_mag(&self.mag, bits));
+		if self.neg && r.shl(bits) != *self {
+			return r.sub(&BigInt::from_u128(1));
+		}
+
+		r
+	}
+
+	pub fn parse(s: &str, radix: u32) -> Option<BigInt> {
+		let (neg, digits) = match s.strip_prefix('-') {
+			Some(rest) => (true, rest),
+			None => (false, s),
+		};
+
+		if digits.is_empty() {
+			return None;
+		}
+
+		let mut mag: Vec<u32> = Vec::new();
+		for c in digits.chars() {
+			if c == '_' {
+				continue
+			}
+
+			let d: u32 = c.to_digit(radix)?;
+
+			let mut carry: u64 = d as u64;
+			for x in mag.iter_mut() {
+				let t: u64 = *x as u64 * radix as u64 + carry;
+				*x = t as u32;
+				carry = t >> 32;
+			}
+
+			if carry > 0 {
+				mag.push(carry as u32);
+			}
+		}
+
+		Some(BigInt::from_mag(neg, mag))
+	}
+
+	pub fn to_string_radix(&self, radix: u32) -> String {
+		if self.is_zero() {
+			return String::from("0");
+		}
+
+		// biggest power of radix which fits into limb //;
+		let (mut chunk, mut width): (u32, usize) = (radix, 1);
+		while let Some(c) = chunk.checked_mul(radix) {
+			chunk = c;
+			width += 1;
+		}
+
+		let mut parts: Vec<u32> = Vec::new();
+		let mut mag: Vec<u32> = self.mag.clone();
+		while !mag.is_empty() {
+			let (q, r) = divrem_small(&mag, chunk);
+			parts.push(r);
+			mag = q;
+		}
+
+		let mut s: String = String::new();
+		if self.neg {
+			s.push('-');
+		}
+
+		for (index, part) in parts.iter().rev().enumerate() {
+			let mut digits: Vec<char> = Vec::new();
+			let mut p: u32 = *part;
+			while p > 0 {
+				digits.push(std::char::from_digit(p % radix, radix).unwrap());
+				p /= radix;
+			}
+
+			if index > 0 {
+				for _ in digits.len()..width {
+					s.push('0');
+				}
+			}
+
+			s.extend(digits.iter().rev());
+		}
+
+		s
+	}
+}
+
+impl Ord for BigInt {
+	fn cmp(&self, o: &BigInt) -> Ordering {
+		match (self.neg, o.neg) {
+			(false, true) => Ordering::Greater,
+			(true, false) => Ordering::Less,
+			(false, false) => cmp_mag(&self.mag, &o.mag),
+			(true, true) => cmp_mag(&o.mag, &self.mag),
+		}
+	}
+}
+
+impl PartialOrd for BigInt {
+	fn partial_cmp(&self, o: &BigInt) -> Option<Ordering> {
+		Some(self.cmp(o))
+	}
+}
+
+impl fmt::Display for BigInt {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.to_string_radix(10))
+	}
+}
@@ -0,0 +1,262 @@
+// `format` builtin
+// placeholders: {} next argument, {0} argument by index, {name} variable made by `mut`
+//   ident given as argument is also read from variable
+// spec after `:` is [[fill]align][0][width][.prec][type]
+//   align is < left, > right or ^ center, numbers are aligned right by default
+//   0 pads number with zeros after sign
+//   .prec cuts string to prec chars, or makes number at least prec digits long
+//   type is x, X, o or b for numbers in other radix
+// {{ and }} are literal braces //;
+
+use super::bigint;
+use super::tokenizer::Token;
+use super::vars;
+
+struct Spec {
+	fill: char,
+	align: Option<char>,
+	zero: bool,
+	width: usize,
+	prec: Option<usize>,
+	radix: Option<char>,
+}
+
+fn parse_spec(s: &str) -> Result<Spec, String> {
+	let cs: Vec<char> = s.chars().collect();
+	let mut spec: Spec = Spec {
+		fill: ' ',
+		align: None,
+		zero: false,
+		width: 0,
+		prec: None,
+		radix: None,
+	};
+
+	let mut pos: usize = 0;
+	let is_align = |c: char| c == '<' || c == '>' || c == '^';
+	if cs.len() > 1 && is_align(cs[1]) {
+		spec.fill = cs[0];
+		spec.align = Some(cs[1]);
+		pos = 2;
+	} else if !cs.is_empty() && is_align(cs[0]) {
+		spec.align = Some(cs[0]);
+		pos = 1;
+	}
+
+	if pos < cs.len() && cs[pos] == '0' {
+		spec.zero = true;
+		pos += 1;
+	}
+
+	let number = |pos: &mut usize| -> Option<usize> {
+		let start: usize = *pos;
+		while *pos < cs.len() && cs[*pos].is_ascii_digit() {
+			*pos += 1;
+		}
+
+		cs[start..*pos].iter().collect::<String>().parse().ok()
+	};
+
+	spec.width = number(&mut pos).unwrap_or(0);
+	if pos < cs.len() && cs[pos] == '.' {
+		pos += 1;
+		match number(&mut pos) {
+			Some(p) => spec.prec = Some(p),
+			None => return Err(format!("precision expected in `{{:{}}}`", s)),
+		}
+	}
+
+	if pos < cs.len() && "xXob".contains(cs[pos]) {
+		spec.radix = Some(cs[pos]);
+		pos += 1;
+	}
+
+	if pos != cs.len() {
+		return Err(format!("invalid format spec `{{:{}}}`", s));
+	}
+
+	Ok(spec)
+}
+
+fn show(t: &Token) -> Option<String> {
+	match t {
+		Token::Str(s) => Some(s.clone()),
+		Token::Digit(d) => Some(format!("{}", d)),
+		Token::Nil => Some(String::from("nil")),
+		Token::Bool(b) => Some(format!("{}", b)),
+		Token::Big(b) => Some(format!("{}", b)),
+		Token::Record(r) => Some(r.show()),
+		Token::Vector(v) => Some(v.show()),
+		Token::Set(s) => Some(s.show()),
+		_ => None,
+	}
+}
+
+fn number(t: &Token) -> Option<bigint::BigInt> {
+	match t {
+		Token::Digit(d) => Some(bigint::BigInt::from_u128(*d)),
+		Token::Big(b) => Some(b.clone()),
+		_ => None,
+	}
+}
+
+fn pad(s: String, width: usize, fill: char, align: char) -> String {
+	let n: usize = s.chars().count();
+	if n >= width {
+		return s;
+	}
+
+	let fill_n = |k: usize| -> String { std::iter::repeat_n(fill, k).collect() };
+	match align {
+		'<' => s + &fill_n(width-n),
+		'^' => fill_n((width-n)/2) + &s + &fill_n(width-n - (width-n)/2),
+		_ => fill_n(width-n) + &s,
+	}
+}
+
+fn apply(vals: &[Token], spec: &Spec) -> Result<String, String> {
+	let num: Option<bigint::BigInt> = if vals.len() == 1 { number(&vals[0]) } else { None };
+
+	if let Some(b) = num {
+		let radix: u32 = match spec.radix {
+			Some('x')|Some('X') => 16,
+			Some('o') => 8,
+			Some('b') => 2,
+			_ => 10,
+		};
+
+		let mut digits: String = b.abs().to_string_radix(radix);
+		if spec.radix == Some('X') {
+			digits = digits.to_uppercase();
+		}
+
+		if let Some(p) = spec.prec {
+			digits = pad(digits, p, '0', '>');
+		}
+
+		let sign: &str = if b.is_neg() { "-" } else { "" };
+		if spec.zero && spec.align.is_none() {
+			let width: usize = spec.width.saturating_sub(sign.len());
+			return Ok(String::from(sign) + &pad(digits, width, '0', '>'));
+		}
+
+		return Ok(pad(String::from(sign) + &digits, spec.width, spec.fill,
+									spec.align.unwrap_or('>')));
+	}
+
+	if let Some(r) = spec.radix {
+		return Err(format!("number expected for radix `{}`", r));
+	}
+
+	let mut parts: Vec<String> = Vec::new();
+	for t in vals.iter() {
+		match show(t) {
+			Some(s) => parts.push(s),
+			None => return Err(String::from("value can't be formatted")),
+		}
+	}
+
+	let mut s: String = parts.join(" ");
+	if let Some(p) = spec.prec {
+		s = s.chars().take(p).collect();
+	}
+
+	Ok(pad(s, spec.width, if spec.zero { '0' } else { spec.fill },
+								spec.align.unwrap_or('<')))
+}
+
+// single value of f-string expression, several values are joined by space
+//   and nothing is empty string //;
+pub fn group(vals: &[Token]) -> Result<Token, String> {
+	if vals.len() == 1 {
+		return Ok(vals[0].clone());
+	}
+
+	let mut parts: Vec<String> = Vec::new();
+	for t in vals.iter() {
+		match show(t) {
+			Some(s) => parts.push(s),
+			None => return Err(String::from("value can't be formatted")),
+		}
+	}
+
+	Ok(Token::Str(parts.join(" ")))
+}
+
+// ident argument stands for variable with that name //;
+fn arg<'a>(args: &'a [Token], index: usize, vars: &'a vars::Vars) -> Result<&'a [Token], String> {
+	match args.get(index) {
+		Some(Token::Ident(name)) => match vars.get(name) {
+			Some(val) => Ok(val),
+			None => Err(format!("no variable `{}`", name)),
+		},
+		Some(_) => Ok(&args[index..index+1]),
+		None => Err(format!("no argument for placeholder {}", index)),
+	}
+}
+
+pub fn format(fmt: &str, args: &[Token], vars: &vars::Vars) -> Result<String, String> {
+	let cs: Vec<char> = fmt.chars().collect();
+	let mut res: String = String::new();
+	let mut next: usize = 0;
+	let mut pos: usize = 0;
+
+	while pos < cs.len() {
+		let c: char = cs[pos];
+		pos += 1;
+
+		if c == '}' {
+			if pos < cs.len() && cs[pos] == '}' {
+				pos += 1;
+				res.push('}');
+				continue
+			}
+
+			return Err(String::from("unmatched `}`, use `}}` for literal brace"));
+		}
+
+		if c != '{' {
+			res.push(c);
+			continue
+		}
+
+		if pos < cs.len() && cs[pos] == '{' {
+			pos += 1;
+			res.push('{');
+			continue
+		}
+
+		let start: usize = pos;
+		while pos < cs.len() && cs[pos] != '}' {
+			pos += 1;
+		}
+
+		if pos == cs.len() {
+			return Err(String::from("unclosed `{`"));
+		}
+
+		let inner: String = cs[start..pos].iter().collect();
+		pos += 1;
+
+		let (name, spec): (&str, &str) = match inner.find(':') {
+			Some(k) => (&inner[..k], &inner[k+1..]),
+			None => (inner.as_str(), ""),
+		};
+
+		let spec: Spec = parse_spec(spec)?;
+		let name: &str = name.trim();
+
+		if name.is_empty() {
+			res += &apply(arg(args, next, vars)?, &spec)?;
+			next += 1;
+		} else if let Ok(index) = name.parse::<usize>() {
+			res += &apply(arg(args, index, vars)?, &spec)?;
+		} else if let Some(val) = vars.get(name) {
+			res += &apply(val, &spec)?;
+		} else {
+			return Err(format!("no variable `{}`", name));
+		}
+	}
+
+	Ok(res)
+}
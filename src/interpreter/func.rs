@@ -0,0 +1,89 @@
+use super::tokenizer::Token;
+
+// function made by `let`
+// body refers to parameters through (% i), so signature only arranges
+//   given arguments into slots: positional ones, then keywords, then rest //;
+#[derive(Clone)]
+pub struct Func {
+	pub code: Vec<Token>,
+	pub sig: Option<Sig>,
+	// types of methods added by `defmethod`, only generic functions have them //;
+	pub methods: Vec<Vec<String>>,
+}
+
+// functions with plain parameters get signature where all of them are
+//   required and the last one is rest
+// only functions without parameters have no signature //;
+#[derive(Clone)]
+pub struct Sig {
+	pub positional: usize,
+	pub required: usize,
+	pub keywords: Vec<String>,
+	// default value code for every slot, None for required parameters //;
+	pub defaults: Vec<Option<Vec<Token>>>,
+	pub rest: bool,
+	pub show: String,
+}
+
+impl Func {
+	pub fn new(code: Vec<Token>) -> Func {
+		Func {
+			code,
+			sig: None,
+			methods: Vec::new(),
+		}
+	}
+}
+
+impl Sig {
+	pub fn slots(&self) -> usize {
+		self.positional + self.keywords.len()
+	}
+
+	// None in slot means that default value should be used //;
+	pub fn arrange(&self, given: Vec<Token>) -> Result<(Vec<Option<Token>>, Vec<Token>), String> {
+		let mut slots: Vec<Option<Token>> = vec![None; self.slots()];
+		let mut positional: Vec<Token> = Vec::new();
+		let mut it = given.into_iter();
+
+		while let Some(t) = it.next() {
+			let kw: Option<String> = match t {
+				Token::Ident(ref s) if !self.keywords.is_empty() && s.starts_with(':') => Some(s[1..].to_string()),
+				_ => None,
+			};
+
+			if let Some(k) = kw {
+				let index: usize = match self.keywords.iter().position(|n| *n == k) {
+					Some(index) => index,
+					None => return Err(format!("unknown keyword `:{}`", k)),
+				};
+
+				match it.next() {
+					Some(v) => slots[self.positional + index] = Some(v),
+					None => return Err(format!("no value for keyword `:{}`", k)),
+				}
+
+				continue
+			}
+
+			positional.push(t);
+		}
+
+		let given: usize = positional.len();
+		if given < self.required || (!self.rest && given > self.positional) {
+			return Err(format!("{} given", given));
+		}
+
+		let rest: Vec<Token> = if given > self.positional {
+			positional.split_off(self.positional)
+		} else {
+			Vec::new()
+		};
+
+		for (index, t) in positional.into_iter().enumerate() {
+			slots[index] = Some(t);
+		}
+
+		Ok((slots, rest))
+	}
+}
@@ -0,0 +1,100 @@
+// simplified extended grapheme cluster segmentation (UAX #29)
+// handles CR LF, combining marks, variation selectors, emoji modifiers,
+//   ZWJ emoji sequences, regional indicator pairs (flags) and hangul jamo
+// it's not complete unicode table, but covers most of real text //;
+
+fn is_extend(c: char) -> bool {
+	matches!(c as u32,
+		0x0300..=0x036F|0x0483..=0x0489|0x0591..=0x05BD|0x05BF|0x05C1..=0x05C2|
+		0x05C4..=0x05C5|0x05C7|0x0610..=0x061A|0x064B..=0x065F|0x0670|
+		0x06D6..=0x06DC|0x06DF..=0x06E4|0x06E7..=0x06E8|0x06EA..=0x06ED|
+		0x0900..=0x0903|0x093A..=0x094F|0x0951..=0x0957|0x0962..=0x0963|
+		0x0981..=0x0983|0x09BC..=0x09CD|0x0E31|0x0E34..=0x0E3A|0x0E47..=0x0E4E|
+		0x1AB0..=0x1AFF|0x1DC0..=0x1DFF|0x200C..=0x200D|0x20D0..=0x20FF|
+		0x302A..=0x302F|0x3099..=0x309A|0xFE00..=0xFE0F|0xFE20..=0xFE2F|
+		0x1F3FB..=0x1F3FF|0xE0020..=0xE007F|0xE0100..=0xE01EF)
+}
+
+fn is_pictographic(c: char) -> bool {
+	matches!(c as u32,
+		0x00A9|0x00AE|0x203C|0x2049|0x2122|0x2139|0x2194..=0x21AA|
+		0x231A..=0x23FF|0x24C2|0x25AA..=0x25FE|0x2600..=0x27BF|0x2934..=0x2935|
+		0x2B05..=0x2B55|0x3030|0x303D|0x3297|0x3299|0x1F000..=0x1FAFF)
+}
+
+fn is_regional(c: char) -> bool {
+	(0x1F1E6..=0x1F1FF).contains(&(c as u32))
+}
+
+enum Hangul {
+	L,
+	V,
+	T,
+	Lv,
+	Lvt,
+	Other,
+}
+
+fn hangul(c: char) -> Hangul {
+	match c as u32 {
+		0x1100..=0x115F|0xA960..=0xA97C => Hangul::L,
+		0x1160..=0x11A7|0xD7B0..=0xD7C6 => Hangul::V,
+		0x11A8..=0x11FF|0xD7CB..=0xD7FB => Hangul::T,
+		d @ 0xAC00..=0xD7A3 => if (d - 0xAC00) % 28 == 0 { Hangul::Lv } else { Hangul::Lvt },
+		_ => Hangul::Other,
+	}
+}
+
+// should `c` stay in the same cluster as `cluster`, last char of which is `prev` //;
+fn joins(cluster: &str, prev: char, c: char) -> bool {
+	if prev == '\r' {
+		return c == '\n';
+	}
+
+	if prev == '\n' || c == '\r' || c == '\n' {
+		return false;
+	}
+
+	if is_extend(c) {
+		return true;
+	}
+
+	if prev == '\u{200D}' && is_pictographic(c) {
+		return cluster.chars().any(is_pictographic);
+	}
+
+	if is_regional(prev) && is_regional(c) {
+		return cluster.chars().filter(|&ch| is_regional(ch)).count() % 2 == 1;
+	}
+
+	matches!((hangul(prev), hangul(c)),
+		(Hangul::L, Hangul::L)|(Hangul::L, Hangul::V)|(Hangul::L, Hangul::Lv)|
+		(Hangul::L, Hangul::Lvt)|(Hangul::V, Hangul::V)|(Hangul::V, Hangul::T)|
+		(Hangul::Lv, Hangul::V)|(Hangul::Lv, Hangul::T)|(Hangul::T, Hangul::T)|
+		(Hangul::Lvt, Hangul::T))
+}
+
+pub fn graphemes(s: &str) -> Vec<String> {
+	let mut res: Vec<String> = Vec::new();
+	let mut cur: String = String::new();
+
+	for c in s.chars() {
+		let join: bool = match cur.chars().last() {
+			Some(prev) => joins(&cur, prev, c),
+			None => true,
+		};
+
+		if !join {
+			res.push(cur);
+			cur = String::new();
+		}
+
+		cur.push(c);
+	}
+
+	if !cur.is_empty() {
+		res.push(cur);
+	}
+
+	res
+}
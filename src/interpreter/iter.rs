@@ -0,0 +1,248 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{Receiver, Sender};
+use super::func::Func;
+use super::tokenizer::Token;
+
+// lazy sequence
+// all copies of iterator token share the same state, so taking element
+//   from one copy advances every copy
+// sources produce elements by themselves, adapters pull them from other
+//   iterator and may call functions, so they are driven by interpreter //;
+pub struct Lazy(pub Arc<Mutex<Iter>>);
+
+pub struct Iter {
+	pub src: Source,
+	pub buf: VecDeque<Token>,
+	// value for `yield` of generator, given by `send` //;
+	pub send_val: Option<Vec<Token>>,
+	// error raised inside of generator, should be raised by consumer //;
+	pub err: Option<String>,
+}
+
+pub enum Source {
+	Range { next: u128, end: Option<u128>, step: u128 },
+	Chars { s: String, pos: usize },
+	Bytes { s: String, pos: usize },
+	Lines(io::Lines<io::BufReader<fs::File>>),
+	Stdin,
+	Gen(Gen),
+	Adapter(Adapter, Lazy),
+	// elements given to `iter`, they are kept in buffer //;
+	Items,
+	Done,
+}
+
+// generator runs in its own thread and waits for resume after every `yield`
+// interpreter is recursive, so only its own stack lets generator stop in
+//   the middle of body //;
+pub enum GenMsg {
+	Yield(Vec<Token>, Shared),
+	Done(Shared),
+	Error(String, Shared),
+}
+
+// state which is passed to generator with every resume and given back
+//   with every message, consumer waits while generator runs, so functions,
+//   lambdas and globals are never used by both of them at once //;
+#[derive(Default)]
+pub struct Shared {
+	pub funcs: HashMap<String, Func>,
+	pub lambdas: usize,
+	pub globals: HashMap<String, Vec<Token>>,
+}
+
+// consumer side of generator //;
+pub struct Gen {
+	pub resume: Sender<(Vec<Token>, Shared)>,
+	pub values: Receiver<GenMsg>,
+}
+
+// generator side, kept in flow of generator thread //;
+pub struct GenLink {
+	pub values: Sender<GenMsg>,
+	pub resume: Receiver<(Vec<Token>, Shared)>,
+}
+
+#[derive(Clone)]
+pub enum Adapter {
+	Map(Token),
+	Filter(Token),
+	TakeWhile(Token),
+	DropWhile(Token),
+	Enumerate(u128),
+	Take(u128),
+	Skip(u128),
+}
+
+impl Lazy {
+	pub fn new(src: Source) -> Lazy {
+		Lazy(Arc::new(Mutex::new(Iter {
+			src,
+			buf: VecDeque::new(),
+			send_val: None,
+			err: None,
+		})))
+	}
+
+	pub fn adapt(a: Adapter, inner: Lazy) -> Lazy {
+		Lazy::new(Source::Adapter(a, inner))
+	}
+
+	// sequences which were plain lists before iterators: range with end,
+	//   chars and bytes, and adapters over them
+	// such ones are expanded when stored, generators, lines, endless ranges
+	//   and iterators made by `iter` stay iterators //;
+	pub fn is_list(&self) -> bool {
+		let it = self.0.lock().unwrap();
+		match it.src {
+			Source::Range { end, .. } => end.is_some(),
+			Source::Chars { .. }|Source::Bytes { .. } => true,
+			Source::Adapter(_, ref inner) => inner.is_list(),
+			Source::Lines(_)|Source::Stdin|Source::Gen(_)|Source::Items|Source::Done => false,
+		}
+	}
+}
+
+impl Clone for Lazy {
+	fn clone(&self) -> Lazy {
+		Lazy(self.0.clone())
+	}
+}
+
+impl PartialEq for Lazy {
+	fn eq(&self, o: &Lazy) -> bool {
+		Arc::ptr_eq(&self.0, &o.0)
+	}
+}
+
+impl Eq for Lazy {}
+
+impl Hash for Lazy {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		(Arc::as_ptr(&self.0) as usize).hash(state);
+	}
+}
+
+impl Iter {
+	// Some(next element) for sources and buffered elements,
+	//   None if adapter should be driven by interpreter
+	// shared state is lent to generator until it gives next element //;
+	pub fn produce(&mut self, shared: &mut Shared) -> Option<Option<Token>> {
+		if let Some(t) = self.buf.pop_front() {
+			return Some(Some(t));
+		}
+
+		if let Source::Gen(_) = self.src {
+			return Some(self.resume(shared));
+		}
+
+		let res: Option<Token> = match self.src {
+			Source::Range { ref mut next, ref mut end, step } => {
+				if end.is_some_and(|e| *next >= e) {
+					None
+				} else {
+					let d: u128 = *next;
+					match d.checked_add(step) {
+						Some(n) => *next = n,
+						None => *end = Some(d),
+					}
+
+					Some(Token::Digit(d))
+				}
+			}
+
+			Source::Chars { ref s, ref mut pos } => {
+				s[*pos..].chars().next().map(|c| {
+					*pos += c.len_utf8();
+					Token::Str(String::from(c))
+				})
+			}
+
+			Source::Bytes { ref s, ref mut pos } => {
+				s.as_bytes().get(*pos).map(|b| {
+					*pos += 1;
+					Token::Digit(*b as u128)
+				})
+			}
+
+			Source::Lines(ref mut lines) => {
+				match lines.next() {
+					Some(Ok(l)) => Some(Token::Str(l)),
+					_ => None,
+				}
+			}
+
+			Source::Stdin => {
+				let mut l: String = String::new();
+				match io::stdin().read_line(&mut l) {
+					Ok(n) if n > 0 => Some(Token::Str(String::from(l.trim_end_matches(['\n', '\r'])))),
+					_ => None,
+				}
+			}
+
+			Source::Gen(_)|Source::Adapter(..) => {
+				return None;
+			}
+
+			Source::Items|Source::Done => None,
+		};
+
+		if res.is_none() {
+			self.src = Source::Done;
+		}
+
+		Some(res)
+	}
+
+	fn resume(&mut self, shared: &mut Shared) -> Option<Token> {
+		loop {
+			let msg: Option<GenMsg> = match self.src {
+				Source::Gen(ref g) => {
+					let v: Vec<Token> = self.send_val.take().unwrap_or_default();
+					match g.resume.send((v, mem::take(shared))) {
+						Ok(()) => g.values.recv().ok(),
+						Err(e) => {
+							*shared = (e.0).1;
+							None
+						}
+					}
+				}
+
+				_ => None,
+			};
+
+			match msg {
+				Some(GenMsg::Yield(v, s)) => {
+					*shared = s;
+					self.buf.extend(v);
+					if let Some(t) = self.buf.pop_front() {
+						return Some(t);
+					}
+				}
+
+				Some(GenMsg::Error(e, s)) => {
+					*shared = s;
+					self.err = Some(e);
+					self.src = Source::Done;
+					return None;
+				}
+
+				Some(GenMsg::Done(s)) => {
+					*shared = s;
+					self.src = Source::Done;
+					return None;
+				}
+
+				None => {
+					self.src = Source::Done;
+					return None;
+				}
+			}
+		}
+	}
+}
@@ -1,10 +1,30 @@
+mod bigint;
+mod format;
+mod func;
+mod grapheme;
+mod iter;
+mod pattern;
+mod record;
+mod set;
 mod tokenizer;
+mod vars;
+mod vector;
 use std::fs;
 use std::mem;
 use std::env;
 use std::io::{self, Write};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::process;
+use std::sync::{mpsc, Arc};
+use std::panic;
+use std::thread;
+
+// `pow` without modulus gives error for bigger results //;
+const POW_MAX_BITS: usize = 1 << 20;
+
+// `str-repeat` gives error for longer strings //;
+const STR_MAX_LEN: usize = 1 << 30;
 
 macro_rules! unless {
 	(let $pat:pat = $expr:expr, $block:block) => {
@@ -91,871 +111,3626 @@ macro_rules! make_err {
 
 		process::exit(8);
 	};
+
+	(arity, $i:ident, $given:expr, $params:expr) => {
+		eprintln!("ERR: wrong arguments for `{}`: {}, parameters are ({})",
+							$i, $given, $params);
+
+		process::exit(1);
+	};
+
+	(outside, $i:ident, $scope:expr) => {
+		eprintln!("ERR: `{}` used outside of {}",
+							$i, $scope);
+
+		process::exit(9);
+	};
 }
 
-pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
-									depth: usize,
-									args: &Vec<tokenizer::Token>,
-									funcs: &mut HashMap<String, Vec<tokenizer::Token>>,
-									lambdas: &mut usize,
-									vars: &mut HashMap<String, Vec<tokenizer::Token>>,
-) -> Vec<tokenizer::Token> {
-	let mut stack: Vec<tokenizer::Token> = Vec::new();
-	let mut ret: Vec<tokenizer::Token> = Vec::new();
-	let mut catch_vec: Vec<Vec<tokenizer::Token>> = Vec::new();
-	let mut deep: u16 = 0;
-	let mut in_catch: bool = false;
+// handles signal left by loop body, should be used inside of loop //;
+macro_rules! loop_signal {
+	($flow:ident, $ret:ident) => {
+		match mem::replace(&mut $flow.signal, Signal::Normal) {
+			Signal::Normal|Signal::Continue => {
+				// VOID //;
+			}
 
-	if depth > 0 {
-		if let tokenizer::Token::Ident(ref s) = tokens[0] {
-			match s.as_str() {
-				"if"|"let"|"bind"|"each"|"case"|"mut"|"set"|"while" => {
-					in_catch = true;
-				}
+			Signal::Break(v) => {
+				$ret.extend(v);
+				break
+			}
 
-				"lambda" => {
-					let lambda_ident: tokenizer::Token =
-							tokenizer::Token::Ident(format!("{}l", *lambdas));
+			sig => {
+				$flow.signal = sig;
+				return $ret;
+			}
+		}
+	};
+}
 
-					*lambdas += 1;
+// raises catchable error, see `try` //;
+macro_rules! raise {
+	($flow:ident, $($fmt:tt)*) => {
+		$flow.signal = Signal::Error(format!($($fmt)*));
+		return vec![];
+	};
+}
 
-					let mut tmp: Vec<tokenizer::Token> = tokens.clone();
-					tmp.remove(0);
-					tmp.insert(0, lambda_ident.clone());
-					tmp.insert(0, tokenizer::Token::Ident(String::from("let")));
-					run_tokens(&tmp, depth+1, args, funcs, lambdas, vars);
-					return vec![lambda_ident.clone()];
-				}
+pub enum Signal {
+	Normal,
+	Break(Vec<tokenizer::Token>),
+	Continue,
+	Return(Vec<tokenizer::Token>),
+	Error(String),
+}
 
-				"cond" => {
-					let mut tmp: Vec<tokenizer::Token> = tokens.clone();
-					tmp.insert(0, tokenizer::Token::Digit(1));
-					tmp.insert(0, tokenizer::Token::Ident(String::from("case")));
-					return run_tokens(&tmp, depth+1, args, funcs, lambdas, vars);
-				}
+// state of `break`, `continue`, `return` and raised errors,
+//   loops and funcs are nesting counters //;
+pub struct Flow {
+	pub signal: Signal,
+	pub loops: usize,
+	pub funcs: usize,
+	// set inside of generator thread //;
+	pub gen: Option<iter::GenLink>,
+}
 
-				_ => {
-					// not a macro //;
-				}
+impl Flow {
+	fn interrupted(&self) -> bool {
+		!matches!(self.signal, Signal::Normal)
+	}
+}
+
+// nil, false, 0 and '' are false, everything else is true //;
+fn token_truthy(t: &tokenizer::Token) -> bool {
+	match t {
+		tokenizer::Token::Nil|tokenizer::Token::Bool(false) => false,
+		tokenizer::Token::Digit(0) => false,
+		tokenizer::Token::Str(s) => !s.is_empty(),
+		_ => true,
+	}
+}
+
+// empty result is false, otherwise every element should be true //;
+fn truthy(v: &[tokenizer::Token]) -> bool {
+	!v.is_empty() && v.iter().all(token_truthy)
+}
+
+// all tokens should be strings, `first` is index of first one for error message //;
+fn str_args(i: &str, toks: Vec<tokenizer::Token>, first: usize) -> Vec<String> {
+	let mut res: Vec<String> = Vec::new();
+	for (index, t) in toks.into_iter().enumerate() {
+		if let tokenizer::Token::Str(s) = t {
+			res.push(s);
+			continue
+		}
+
+		make_err!(argt, i, t, index+first);
+	}
+
+	res
+}
+
+fn char_of(d: u128) -> Option<char> {
+	if d > u32::MAX as u128 {
+		return None;
+	}
+
+	std::char::from_u32(d as u32)
+}
+
+fn big_of(t: &tokenizer::Token) -> Option<bigint::BigInt> {
+	match t {
+		tokenizer::Token::Digit(d) => Some(bigint::BigInt::from_u128(*d)),
+		tokenizer::Token::Big(b) => Some(b.clone()),
+		_ => None,
+	}
+}
+
+// numbers which fit into u128 are always stored as Digit //;
+fn big_token(b: bigint::BigInt) -> tokenizer::Token {
+	match b.to_u128() {
+		Some(d) => tokenizer::Token::Digit(d),
+		None => tokenizer::Token::Big(b),
+	}
+}
+
+// order of kinds: nil, bools, numbers, strings, idents //;
+fn token_rank(t: &tokenizer::Token) -> u8 {
+	match t {
+		tokenizer::Token::Nil => 0,
+		tokenizer::Token::Bool(_) => 1,
+		tokenizer::Token::Digit(_)|tokenizer::Token::Big(_) => 2,
+		tokenizer::Token::Str(_) => 3,
+		tokenizer::Token::Ident(_) => 4,
+		tokenizer::Token::Record(_) => 5,
+		tokenizer::Token::Vector(_) => 6,
+		tokenizer::Token::Set(_) => 7,
+		tokenizer::Token::OPair|tokenizer::Token::CPair|tokenizer::Token::Iter(_) => 8,
+	}
+}
+
+// types that methods of generic function can be defined for, most specific first
+// record is of type of its struct, then of its variant, then `record` //;
+fn type_names(t: &tokenizer::Token) -> Vec<String> {
+	let mut res: Vec<String> = Vec::new();
+	match t {
+		tokenizer::Token::Nil => res.push(String::from("nil")),
+		tokenizer::Token::Bool(_) => res.push(String::from("bool")),
+		tokenizer::Token::Digit(_)|tokenizer::Token::Big(_) => res.push(String::from("int")),
+		tokenizer::Token::Str(_) => res.push(String::from("str")),
+		tokenizer::Token::Ident(_) => res.push(String::from("ident")),
+		tokenizer::Token::Iter(_) => res.push(String::from("iter")),
+		tokenizer::Token::Vector(_) => res.push(String::from("vec")),
+		tokenizer::Token::Set(_) => res.push(String::from("set")),
+		tokenizer::Token::Record(r) => {
+			res.push(r.name.clone());
+			if let Some(ref v) = r.variant {
+				res.push(v.name.clone());
 			}
+
+			res.push(String::from("record"));
 		}
+
+		tokenizer::Token::OPair|tokenizer::Token::CPair => {}
 	}
 
-	for t in tokens.into_iter() {
-		let tmp_t: tokenizer::Token = t.clone();
-		if deep > 0 {
-			match tmp_t {
-				tokenizer::Token::CPair => {
-					deep -= 1;
-				}
+	res.push(String::from("any"));
+	res
+}
 
-				tokenizer::Token::OPair => {
-					deep += 1;
-				}
+// takes `:name value` pairs out of arguments of builtin, values are in order of names //;
+fn keyword_args(ret: &mut Vec<tokenizer::Token>, names: &[&str]) -> Result<Vec<Option<tokenizer::Token>>, String> {
+	let mut res: Vec<Option<tokenizer::Token>> = vec![None; names.len()];
+	let mut k: usize = 0;
+	while k < ret.len() {
+		let index: Option<usize> = match ret[k] {
+			tokenizer::Token::Ident(ref s) if s.starts_with(':') => names.iter().position(|n| *n == &s[1..]),
+			_ => None,
+		};
+
+		match index {
+			Some(index) if k + 1 < ret.len() => {
+				res[index] = Some(ret.remove(k+1));
+				ret.remove(k);
+			}
 
-				_ => {
-					// VOID //;
+			Some(index) => {
+				return Err(format!("no value for keyword `:{}`", names[index]));
+			}
+
+			None => k += 1,
+		}
+	}
+
+	Ok(res)
+}
+
+// function name of method of generic function for given types //;
+fn method_name(generic: &str, types: &[String]) -> String {
+	format!("{}({})", generic, types.join(" "))
+}
+
+// total order of values, strings are compared by code points //;
+fn cmp_tokens(a: &tokenizer::Token, b: &tokenizer::Token) -> Ordering {
+	match (a, b) {
+		(tokenizer::Token::Bool(x), tokenizer::Token::Bool(y)) => x.cmp(y),
+		(tokenizer::Token::Digit(x), tokenizer::Token::Digit(y)) => x.cmp(y),
+		(tokenizer::Token::Str(x), tokenizer::Token::Str(y)) => x.cmp(y),
+		(tokenizer::Token::Ident(x), tokenizer::Token::Ident(y)) => x.cmp(y),
+		(tokenizer::Token::Record(x), tokenizer::Token::Record(y)) => x.name.cmp(&y.name).then_with(|| {
+			let xs: Vec<tokenizer::Token> = x.fields.iter().map(|f| f.1.clone()).collect();
+			let ys: Vec<tokenizer::Token> = y.fields.iter().map(|f| f.1.clone()).collect();
+			cmp_seqs(&xs, &ys)
+		}),
+		(tokenizer::Token::Vector(x), tokenizer::Token::Vector(y)) => cmp_seqs(&x.items(), &y.items()),
+		(tokenizer::Token::Set(x), tokenizer::Token::Set(y)) => {
+			let mut xs: Vec<tokenizer::Token> = x.items();
+			let mut ys: Vec<tokenizer::Token> = y.items();
+			xs.sort_by(cmp_tokens);
+			ys.sort_by(cmp_tokens);
+			cmp_seqs(&xs, &ys)
+		}
+		_ if token_rank(a) == 2 && token_rank(b) == 2 => big_of(a).cmp(&big_of(b)),
+		_ => token_rank(a).cmp(&token_rank(b)),
+	}
+}
+
+// element-wise, shorter sequence is less if it's prefix of longer one //;
+fn cmp_seqs(a: &[tokenizer::Token], b: &[tokenizer::Token]) -> Ordering {
+	for (x, y) in a.iter().zip(b.iter()) {
+		let o: Ordering = cmp_tokens(x, y);
+		if o != Ordering::Equal {
+			return o;
+		}
+	}
+
+	a.len().cmp(&b.len())
+}
+
+// evaluates every operand of form separately, so lists keep their bounds //;
+fn eval_operands(tokens: &[tokenizer::Token],
+								 depth: usize,
+								 args: &Vec<tokenizer::Token>,
+								 funcs: &mut HashMap<String, func::Func>,
+								 lambdas: &mut usize,
+								 vars: &mut vars::Vars,
+								 flow: &mut Flow,
+) -> Vec<Vec<tokenizer::Token>> {
+	let mut res: Vec<Vec<tokenizer::Token>> = Vec::new();
+	let mut stack: Vec<tokenizer::Token> = Vec::new();
+	let mut deep: u16 = 0;
+
+	for t in tokens.iter() {
+		match t {
+			tokenizer::Token::OPair => {
+				if deep > 0 {
+					stack.push(t.clone());
 				}
+
+				deep += 1;
 			}
 
-			if deep == 0 {
-				if in_catch {
-					catch_vec.push(stack.clone());
+			tokenizer::Token::CPair => {
+				deep -= 1;
+				if deep > 0 {
+					stack.push(t.clone());
 				} else {
-					if depth == 0 {
-						ret = run_tokens(&stack, depth+1, args, funcs, lambdas, vars);
-					} else {
-						for i in run_tokens(&stack, depth+1, args, funcs, lambdas, vars)
-							.into_iter() {
-							ret.push(i.clone());
-						}
+					res.push(run_forced(&stack, depth+1, args, funcs, lambdas, vars, flow));
+					stack.clear();
+					if flow.interrupted() {
+						return res;
 					}
 				}
-				stack.clear();
-			} else {
-				stack.push(tmp_t);
 			}
-		} else if tmp_t == tokenizer::Token::OPair {
-			deep = 1;
-		} else if in_catch {
-			catch_vec.push(vec![tmp_t]);
-		} else {
-			ret.push(tmp_t);
+
+			_ if deep > 0 => {
+				stack.push(t.clone());
+			}
+
+			_ => {
+				res.push(force(vec![t.clone()], depth, funcs, lambdas, vars, flow));
+			}
 		}
 	}
 
-	if in_catch {
-		let fun: tokenizer::Token = catch_vec.remove(0).remove(0);
+	res
+}
 
-		match fun {
-			tokenizer::Token::Ident(i) => {
-				match i.as_str() {
-					"while" => {
-						let vl: usize = catch_vec.len();
-						if vl != 2 {
-							make_err!(argcn, i, vl, 2);
-						}
+// calls `f` with `fargs` as if it was written as (f fargs...)
+// arguments are already values, so arguments of caller aren't needed //;
+fn call(f: &tokenizer::Token,
+				fargs: Vec<tokenizer::Token>,
+				depth: usize,
+				funcs: &mut HashMap<String, func::Func>,
+				lambdas: &mut usize,
+				vars: &mut vars::Vars,
+				flow: &mut Flow,
+) -> Vec<tokenizer::Token> {
+	let mut form: Vec<tokenizer::Token> = vec![f.clone()];
+	form.extend(fargs);
+	run_tokens(&form, depth+1, &Vec::new(), funcs, lambdas, vars, flow)
+}
 
-						let code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
-						let cond: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+// replaces every `name` in code //;
+fn substitute(code: &mut Vec<tokenizer::Token>, name: &tokenizer::Token, with: &[tokenizer::Token]) {
+	let mut k: usize = 0;
+	while k < code.len() {
+		if code[k] == *name {
+			code.splice(k..k+1, with.iter().cloned());
+			k += with.len();
+			continue
+		}
 
-						loop {
-							let cond_r: tokenizer::Token = run_tokens(
-								&cond,
-								depth+1,
-								args,
-								funcs,
-								lambdas,
-								vars
-							).remove(0);
+		k += 1;
+	}
+}
 
-							match cond_r {
-								tokenizer::Token::Digit(0) => {
-									break
-								}
+// (name fields...), (name? x), (name-field x) and (name-with x :field value...)
+// constructor of variant case also tags record with variant and its cases //;
+fn struct_funcs(name: &str, fields: &[String], variant: Option<(&str, &[String])>) -> Vec<(String, func::Func)> {
+	let call_args: Vec<tokenizer::Token> = vec![
+		tokenizer::Token::OPair,
+		tokenizer::Token::Ident(String::from("%%")),
+		tokenizer::Token::CPair,
+	];
+
+	let sig = |show: String, required: usize, rest: bool| -> Option<func::Sig> {
+		Some(func::Sig {
+			positional: required,
+			required,
+			keywords: Vec::new(),
+			defaults: vec![None; required],
+			rest,
+			show,
+		})
+	};
 
-								tokenizer::Token::Digit(1) => {
-									let code_r: Vec<tokenizer::Token> = run_tokens(
-										&code,
-										depth+1,
-										args,
-										funcs,
-										lambdas,
-										vars
-									);
+	let strs = |v: &[String]| -> Vec<tokenizer::Token> {
+		v.iter().map(|f| tokenizer::Token::Str(f.clone())).collect()
+	};
 
-									for i in code_r.into_iter() {
-										ret.push(i);
-									}
-								}
+	let mut made: Vec<(String, func::Func)> = Vec::new();
+	let mut code: Vec<tokenizer::Token> = vec![
+		tokenizer::Token::Ident(String::from("record-new")),
+		tokenizer::Token::Str(String::from(name)),
+		tokenizer::Token::Digit(fields.len() as u128),
+	];
+
+	code.extend(strs(fields));
+	code.extend(call_args.clone());
+	if let Some((vname, cases)) = variant {
+		let mut tagged: Vec<tokenizer::Token> = vec![
+			tokenizer::Token::Ident(String::from("record-variant")),
+			tokenizer::Token::Str(String::from(vname)),
+		];
+
+		tagged.extend(strs(cases));
+		tagged.push(tokenizer::Token::OPair);
+		tagged.extend(code);
+		tagged.push(tokenizer::Token::CPair);
+		code = tagged;
+	}
 
-								_ => {
-									make_err!(argt, i, cond_r, "cond");
-								}
-							}
-						}
+	made.push((String::from(name), func::Func { code, sig: sig(fields.join(" "), fields.len(), false), methods: Vec::new() }));
 
-						return ret;
-					}
+	let mut code: Vec<tokenizer::Token> = vec![
+		tokenizer::Token::Ident(String::from("record-is?")),
+		tokenizer::Token::Str(String::from(name)),
+	];
 
-					"set" => {
-						let vl: usize = catch_vec.len();
-						if vl != 2 {
-							make_err!(argcn, i, vl, 2);
-						}
+	code.extend(call_args.clone());
+	made.push((format!("{}?", name), func::Func { code, sig: sig(String::from("x"), 1, false), methods: Vec::new() }));
 
-						let res: Vec<tokenizer::Token> = run_tokens(
-							&catch_vec.pop().unwrap(),
-							depth+1,
-							args,
-							funcs,
-							lambdas,
-							vars
-						);
+	for f in fields.iter() {
+		let mut code: Vec<tokenizer::Token> = vec![
+			tokenizer::Token::Ident(String::from("record-get")),
+			tokenizer::Token::Str(String::from(name)),
+			tokenizer::Token::Str(f.clone()),
+		];
 
-						
-						let name_t: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
-						if let tokenizer::Token::Ident(s) = name_t {
-							if !vars.contains_key(&s) {
-								make_err!(unknown_ident, s);
-							}
+		code.extend(call_args.clone());
+		made.push((format!("{}-{}", name, f), func::Func { code, sig: sig(String::from(name), 1, false), methods: Vec::new() }));
+	}
 
-							vars.insert(s, res);
-							return vec![];
-						}
+	let mut code: Vec<tokenizer::Token> = vec![
+		tokenizer::Token::Ident(String::from("record-with")),
+		tokenizer::Token::Str(String::from(name)),
+	];
 
-						make_err!(argt, i, name_t, 1);
-					}
+	code.extend(call_args);
+	made.push((format!("{}-with", name), func::Func {
+		code,
+		sig: sig(format!("{} & updates", name), 1, true),
+		methods: Vec::new(),
+	}));
 
-					"mut" => {
-						let vl: usize = catch_vec.len();
-						if vl != 2 {
-							make_err!(argcn, i, vl, 2);
-						}
+	made
+}
+
+// functions, lambda counter and globals given to generator while it runs //;
+fn lend(funcs: &mut HashMap<String, func::Func>, lambdas: &mut usize, vars: &mut vars::Vars) -> iter::Shared {
+	iter::Shared {
+		funcs: mem::take(funcs),
+		lambdas: *lambdas,
+		globals: mem::take(&mut vars.globals),
+	}
+}
 
-						let res: Vec<tokenizer::Token> = run_tokens(
-							&catch_vec.pop().unwrap(),
-							depth+1,
-							args,
-							funcs,
-							lambdas,
-							vars
-						);
+fn take_back(shared: iter::Shared, funcs: &mut HashMap<String, func::Func>, lambdas: &mut usize, vars: &mut vars::Vars) {
+	*funcs = shared.funcs;
+	*lambdas = shared.lambdas;
+	vars.globals = shared.globals;
+}
 
-						let name_t: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
-						if let tokenizer::Token::Ident(s) = name_t {
-							if vars.contains_key(&s) {
-								make_err!(redef, i, s);
-							}
+// takes next element of iterator, None when it's exhausted or flow is interrupted //;
+fn iter_next(it: &iter::Lazy,
+						 depth: usize,
+						 funcs: &mut HashMap<String, func::Func>,
+						 lambdas: &mut usize,
+						 vars: &mut vars::Vars,
+						 flow: &mut Flow,
+) -> Option<tokenizer::Token> {
+	loop {
+		if flow.interrupted() {
+			return None;
+		}
 
-							vars.insert(s, res);
-							return vec![];
-						} 
+		let (adapter, inner): (iter::Adapter, iter::Lazy) = {
+			let mut g = it.0.lock().unwrap();
+			let mut shared: iter::Shared = lend(funcs, lambdas, vars);
+			let res: Option<Option<tokenizer::Token>> = g.produce(&mut shared);
+			take_back(shared, funcs, lambdas, vars);
 
-						make_err!(argt, i, name_t, 1);
-					}
+			if let Some(res) = res {
+				if let Some(e) = g.err.take() {
+					flow.signal = Signal::Error(e);
+					return None;
+				}
 
-					"case" => {
-						let vl: usize = catch_vec.len();
-						if vl < 3 {
-							make_err!(argc, i, vl, 3, 0);
-						}
+				return res;
+			}
 
-						let val: Vec<tokenizer::Token> =
-								run_tokens(&catch_vec.remove(0), depth+1, args, funcs, lambdas, vars);
+			match g.src {
+				iter::Source::Adapter(ref a, ref inner) => (a.clone(), inner.clone()),
+				_ => unreachable!("source without adapter"),
+			}
+		};
 
-						for pair in catch_vec.chunks(2) {
-							match pair {
-								[case, code]
-								if run_tokens(case, depth+1, args, funcs, lambdas, vars) == val => {
-									return run_tokens(code, depth+1, args, funcs, lambdas, vars);
-								}
+		let set = |src: iter::Source| -> () {
+			it.0.lock().unwrap().src = src;
+		};
 
-								[code] => {
-									return run_tokens(code, depth+1, args, funcs, lambdas, vars);
-								}
+		if let iter::Adapter::Take(0) = adapter {
+			set(iter::Source::Done);
+			return None;
+		}
 
-								_ => {
-									// not matched //;
-								}
-							}
-						}
+		let t: tokenizer::Token = match iter_next(&inner, depth, funcs, lambdas, vars, flow) {
+			Some(t) => t,
+			None => {
+				set(iter::Source::Done);
+				return None;
+			}
+		};
 
-						return vec![];
-					}
-					
-					"if" => {
-						let vl: usize = catch_vec.len();
-						if vl < 2 || vl > 3 {
-							make_err!(argc, i, vl, 2, 3);
-						}
-						
-						let cond: &tokenizer::Token = &run_tokens(&catch_vec.remove(0),
-																											depth+1, args, funcs,
-																											lambdas, vars)[0];
-						match cond {
-							tokenizer::Token::Digit(1) => {
-								return run_tokens(&catch_vec.remove(0), depth+1, args, funcs,
-																	lambdas, vars);
-							}
+		match adapter {
+			iter::Adapter::Take(n) => {
+				set(iter::Source::Adapter(iter::Adapter::Take(n-1), inner));
+				return Some(t);
+			}
 
-							tokenizer::Token::Digit(0) => {
-								if catch_vec.len() > 1 {
-									return run_tokens(&catch_vec.remove(1), depth+1, args,
-																		funcs, lambdas, vars);
-								}
-							}
+			iter::Adapter::Skip(0) => {
+				return Some(t);
+			}
 
-							_ => {
-								make_err!(value, i, cond, "0|1");
-							}
-						}
+			iter::Adapter::Skip(n) => {
+				set(iter::Source::Adapter(iter::Adapter::Skip(n-1), inner));
+			}
 
-						return vec![];
+			iter::Adapter::Enumerate(n) => {
+				let mut g = it.0.lock().unwrap();
+				g.src = iter::Source::Adapter(iter::Adapter::Enumerate(n+1), inner);
+				g.buf.push_back(t);
+				return Some(tokenizer::Token::Digit(n));
+			}
+
+			iter::Adapter::Map(f) => {
+				let r: Vec<tokenizer::Token> = call(&f, vec![t], depth, funcs, lambdas, vars, flow);
+				it.0.lock().unwrap().buf.extend(r);
+			}
+
+			iter::Adapter::Filter(ref f)|iter::Adapter::TakeWhile(ref f)|iter::Adapter::DropWhile(ref f) => {
+				let keep: bool = truthy(&call(f, vec![t.clone()], depth, funcs, lambdas, vars, flow));
+				if flow.interrupted() {
+					return None;
+				}
+
+				match adapter {
+					iter::Adapter::Filter(_) if keep => {
+						return Some(t);
 					}
 
+					iter::Adapter::TakeWhile(_) if keep => {
+						return Some(t);
+					}
 
-					"each" => {
-						let vl: usize = catch_vec.len();
-						
-						if vl != 3 {
+					iter::Adapter::TakeWhile(_) => {
+						set(iter::Source::Done);
+						return None;
+					}
+
+					iter::Adapter::DropWhile(_) if !keep => {
+						set(iter::Source::Adapter(iter::Adapter::Skip(0), inner));
+						return Some(t);
+					}
+
+					_ => {
+						// VOID //;
+					}
+				}
+			}
+		}
+	}
+}
+
+// replaces iterators with all of their elements //;
+fn force(v: Vec<tokenizer::Token>,
+				 depth: usize,
+				 funcs: &mut HashMap<String, func::Func>,
+				 lambdas: &mut usize,
+				 vars: &mut vars::Vars,
+				 flow: &mut Flow,
+) -> Vec<tokenizer::Token> {
+	if !v.iter().any(|t| matches!(t, tokenizer::Token::Iter(_))) {
+		return v;
+	}
+
+	let mut res: Vec<tokenizer::Token> = Vec::new();
+	for t in v.into_iter() {
+		if let tokenizer::Token::Iter(it) = t {
+			while let Some(e) = iter_next(&it, depth, funcs, lambdas, vars, flow) {
+				res.push(e);
+			}
+
+			continue
+		}
+
+		res.push(t);
+	}
+
+	res
+}
+
+// expands only iterators standing for lists, see `iter::Lazy::is_list` //;
+fn settle(v: Vec<tokenizer::Token>,
+					depth: usize,
+					funcs: &mut HashMap<String, func::Func>,
+					lambdas: &mut usize,
+					vars: &mut vars::Vars,
+					flow: &mut Flow,
+) -> Vec<tokenizer::Token> {
+	if !v.iter().any(|t| if let tokenizer::Token::Iter(it) = t { it.is_list() } else { false }) {
+		return v;
+	}
+
+	let mut res: Vec<tokenizer::Token> = Vec::new();
+	for t in v.into_iter() {
+		match t {
+			tokenizer::Token::Iter(ref it) if it.is_list() => {
+				while let Some(e) = iter_next(it, depth, funcs, lambdas, vars, flow) {
+					res.push(e);
+				}
+			}
+
+			_ => res.push(t),
+		}
+	}
+
+	res
+}
+
+// for conditions, which look at every element //;
+fn run_forced(tokens: &Vec<tokenizer::Token>,
+							depth: usize,
+							args: &Vec<tokenizer::Token>,
+							funcs: &mut HashMap<String, func::Func>,
+							lambdas: &mut usize,
+							vars: &mut vars::Vars,
+							flow: &mut Flow,
+) -> Vec<tokenizer::Token> {
+	let v: Vec<tokenizer::Token> = run_tokens(tokens, depth, args, funcs, lambdas, vars, flow);
+	force(v, depth, funcs, lambdas, vars, flow)
+}
+
+pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
+									depth: usize,
+									args: &Vec<tokenizer::Token>,
+									funcs: &mut HashMap<String, func::Func>,
+									lambdas: &mut usize,
+									vars: &mut vars::Vars,
+									flow: &mut Flow,
+) -> Vec<tokenizer::Token> {
+	let mut stack: Vec<tokenizer::Token> = Vec::new();
+	let mut ret: Vec<tokenizer::Token> = Vec::new();
+	let mut catch_vec: Vec<Vec<tokenizer::Token>> = Vec::new();
+	let mut deep: u16 = 0;
+	let mut in_catch: bool = false;
+
+	if flow.interrupted() {
+		return ret;
+	}
+
+	if depth > 0 && !tokens.is_empty() {
+		if let tokenizer::Token::Ident(ref s) = tokens[0] {
+			match s.as_str() {
+				"if"|"let"|"bind"|"each"|"case"|"cond"|"mut"|"set"|"global-mut"|"global-set"|
+				"while"|"and"|"or"|"try" => {
+					in_catch = true;
+				}
+
+				"lambda" => {
+					let lambda_ident: tokenizer::Token =
+							tokenizer::Token::Ident(format!("{}l", *lambdas));
+
+					*lambdas += 1;
+
+					let mut tmp: Vec<tokenizer::Token> = tokens.clone();
+					tmp.remove(0);
+					tmp.insert(0, lambda_ident.clone());
+					tmp.insert(0, tokenizer::Token::Ident(String::from("let")));
+					run_tokens(&tmp, depth+1, args, funcs, lambdas, vars, flow);
+					return vec![lambda_ident.clone()];
+				}
+
+				"defgeneric" => {
+					let vl: usize = tokens.len() - 1;
+					if vl != 1 {
+						make_err!(argcn, s, vl, 1);
+					}
+
+					let name: String = match tokens[1] {
+						tokenizer::Token::Ident(ref n) => n.clone(),
+						ref t => {
+							make_err!(argt, s, t, 1);
+						}
+					};
+
+					if funcs.contains_key(&name) {
+						make_err!(redef, s, name);
+					}
+
+					funcs.insert(name.clone(), func::Func::new(vec![
+						tokenizer::Token::Ident(String::from("call-method")),
+						tokenizer::Token::Str(name),
+						tokenizer::Token::OPair,
+						tokenizer::Token::Ident(String::from("%%")),
+						tokenizer::Token::CPair,
+					]));
+
+					return vec![];
+				}
+
+				// (defmethod name (types...) params... body) is `let` of function
+				//   named after generic and types, (defmethod name default ...) is
+				//   used when no other method applies //;
+				"defmethod" => {
+					let vl: usize = tokens.len() - 1;
+					if vl < 3 {
+						make_err!(argcf, s, vl, 3);
+					}
+
+					let name: String = match tokens[1] {
+						tokenizer::Token::Ident(ref n) => n.clone(),
+						ref t => {
+							make_err!(argt, s, t, 1);
+						}
+					};
+
+					let generic: bool = funcs.get(&name).is_some_and(|f| {
+						f.sig.is_none() && f.code.first() == Some(&tokenizer::Token::Ident(String::from("call-method")))
+					});
+
+					if !generic {
+						let t: tokenizer::Token = tokens[1].clone();
+						make_err!(value, s, t, "generic function made by `defgeneric`");
+					}
+
+					let mut types: Vec<String> = Vec::new();
+					let mut pos: usize = 3;
+					match tokens[2] {
+						tokenizer::Token::Ident(ref d) if d == "default" => {
+							types.push(d.clone());
+						}
+
+						tokenizer::Token::OPair => {
+							while pos < tokens.len() && tokens[pos] != tokenizer::Token::CPair {
+								match tokens[pos] {
+									tokenizer::Token::Ident(ref t) => types.push(t.clone()),
+									ref t => {
+										make_err!(value, s, t, "type name");
+									}
+								}
+
+								pos += 1;
+							}
+
+							pos += 1;
+						}
+
+						ref t => {
+							make_err!(value, s, t, "list of types or `default`");
+						}
+					}
+
+					let mut tmp: Vec<tokenizer::Token> = vec![
+						tokenizer::Token::Ident(String::from("let")),
+						tokenizer::Token::Ident(method_name(&name, &types)),
+					];
+
+					tmp.extend(tokens[pos..].iter().cloned());
+
+					// plain parameters of method are all required, as if `&` ended them //;
+					let amp: tokenizer::Token = tokenizer::Token::Ident(String::from("&"));
+					let mut body: usize = tmp.len() - 1;
+					if tmp[body] == tokenizer::Token::CPair {
+						let mut deep: usize = 0;
+						loop {
+							match tmp[body] {
+								tokenizer::Token::CPair => deep += 1,
+								tokenizer::Token::OPair => deep -= 1,
+								_ => {}
+							}
+
+							if deep == 0 {
+								break
+							}
+
+							body -= 1;
+						}
+					}
+
+					if tmp[2..body].iter().all(|t| matches!(t, tokenizer::Token::Ident(_)) && *t != amp) {
+						tmp.insert(body, amp);
+					}
+
+					run_tokens(&tmp, depth+1, args, funcs, lambdas, vars, flow);
+					if flow.interrupted() {
+						return vec![];
+					}
+
+					funcs.get_mut(&name).unwrap().methods.push(types);
+					return vec![];
+				}
+
+				"defvariant" => {
+					let its: Vec<pattern::Item> = pattern::items(&tokens[1..]);
+					let vl: usize = its.len();
+					if vl < 2 {
+						make_err!(argcf, s, vl, 2);
+					}
+
+					// every case is (case fields...) or bare case without fields //;
+					let mut cases: Vec<Vec<String>> = Vec::new();
+					for (index, it) in its.iter().enumerate() {
+						let toks: Vec<tokenizer::Token> = match it {
+							pattern::Item::Atom(t) => vec![t.clone()],
+							pattern::Item::Form(v) if index > 0 && !v.is_empty() => v.clone(),
+							pattern::Item::Form(_) => {
+								let t: tokenizer::Token = tokenizer::Token::OPair;
+								make_err!(argt, s, t, index+1);
+							}
+						};
+
+						let mut names: Vec<String> = Vec::new();
+						for t in toks.into_iter() {
+							if let tokenizer::Token::Ident(n) = t {
+								names.push(n);
+								continue
+							}
+
+							make_err!(argt, s, t, index+1);
+						}
+
+						cases.push(names);
+					}
+
+					let vname: String = cases.remove(0).remove(0);
+					let case_names: Vec<String> = cases.iter().map(|c| c[0].clone()).collect();
+					let mut made: Vec<(String, func::Func)> = Vec::new();
+					for c in cases.iter() {
+						made.extend(struct_funcs(&c[0], &c[1..], Some((&vname, &case_names))));
+					}
+
+					made.push((format!("{}?", vname), func::Func {
+						code: vec![
+							tokenizer::Token::Ident(String::from("record-variant?")),
+							tokenizer::Token::Str(vname.clone()),
+							tokenizer::Token::OPair,
+							tokenizer::Token::Ident(String::from("%%")),
+							tokenizer::Token::CPair,
+						],
+						sig: None,
+						methods: Vec::new(),
+					}));
+
+					for (fname, f) in made.into_iter() {
+						if funcs.contains_key(&fname) {
+							make_err!(redef, s, fname);
+						}
+
+						funcs.insert(fname, f);
+					}
+
+					return vec![];
+				}
+
+				"match" => {
+					let mut its: Vec<pattern::Item> = pattern::items(&tokens[1..]);
+					let vl: usize = its.len();
+					if vl < 3 {
+						make_err!(argcf, s, vl, 3);
+					}
+
+					let val: Vec<tokenizer::Token> = match its.remove(0) {
+						pattern::Item::Atom(t) => vec![t],
+						pattern::Item::Form(v) => run_forced(&v, depth+1, args, funcs, lambdas, vars, flow),
+					};
+
+					if flow.interrupted() {
+						return vec![];
+					}
+
+					// arms are pattern, optional :when guard and result //;
+					let mut arms: Vec<(pattern::Pat, Option<pattern::Item>, pattern::Item)> = Vec::new();
+					let mut rest = its.into_iter();
+					while let Some(p) = rest.next() {
+						let pat: pattern::Pat = match pattern::parse(&p) {
+							Ok(pat) => pat,
+							Err(e) => {
+								raise!(flow, "`{}`: {}", s, e);
+							}
+						};
+
+						let mut next: Option<pattern::Item> = rest.next();
+						let mut guard: Option<pattern::Item> = None;
+						if let Some(pattern::Item::Atom(tokenizer::Token::Ident(ref w))) = next {
+							if w == ":when" {
+								guard = rest.next();
+								next = rest.next();
+							}
+						}
+
+						match next {
+							Some(res) => {
+								arms.push((pat, guard, res));
+							}
+
+							None => {
+								raise!(flow, "`{}`: every pattern should have result", s);
+							}
+						}
+					}
+
+					if let [tokenizer::Token::Record(ref r)] = val.as_slice() {
+						if let Some(ref v) = r.variant {
+							let mut covered: Vec<String> = Vec::new();
+							let mut all: bool = false;
+							for (pat, guard, _) in arms.iter() {
+								if guard.is_some() {
+									continue
+								}
+
+								match pattern::covers(pat) {
+									Some(names) => covered.extend(names),
+									None => all = true,
+								}
+							}
+
+							let missing: Vec<String> = v.cases.iter()
+								.filter(|c| !covered.contains(c))
+								.cloned()
+								.collect();
+
+							if !all && !missing.is_empty() {
+								raise!(flow, "`{}`: cases of `{}` are not covered: {}", s, v.name, missing.join(", "));
+							}
+						}
+					}
+
+					for (pat, guard, res) in arms.iter() {
+						let mut binds: Vec<(tokenizer::Token, Vec<tokenizer::Token>)> = Vec::new();
+						if !pattern::match_seq(pat, &val, &mut binds) {
+							continue
+						}
+
+						// atom is returned as bound values, form is run with binds substituted //;
+						let subst = |it: &pattern::Item| -> (Vec<tokenizer::Token>, bool) {
+							match it {
+								pattern::Item::Atom(t) => match binds.iter().find(|b| b.0 == *t) {
+									Some(b) => (b.1.clone(), false),
+									None => (vec![t.clone()], false),
+								},
+
+								pattern::Item::Form(v) => {
+									let mut code: Vec<tokenizer::Token> = v.clone();
+									for (name, vals) in binds.iter() {
+										substitute(&mut code, name, vals);
+									}
+
+									(code, true)
+								}
+							}
+						};
+
+						if let Some(g) = guard {
+							let (ok, form) = subst(g);
+							let ok: Vec<tokenizer::Token> = if form {
+								run_forced(&ok, depth+1, args, funcs, lambdas, vars, flow)
+							} else {
+								force(ok, depth, funcs, lambdas, vars, flow)
+							};
+
+							if flow.interrupted() {
+								return vec![];
+							}
+
+							if !truthy(&ok) {
+								continue
+							}
+						}
+
+						let (res, form) = subst(res);
+						if form {
+							return run_tokens(&res, depth+1, args, funcs, lambdas, vars, flow);
+						}
+
+						return res;
+					}
+
+					let shown: Vec<String> = val.iter().map(tokenizer::token_show).collect();
+					raise!(flow, "`{}`: no pattern for {}", s, shown.join(" "));
+				}
+
+				// two single values should be both numbers or both strings,
+				//   lists are compared element by element like in `compare` //;
+				"<"|">"|"<="|">=" => {
+					let mut ops: Vec<Vec<tokenizer::Token>> =
+							eval_operands(&tokens[1..], depth, args, funcs, lambdas, vars, flow);
+
+					if flow.interrupted() {
+						return vec![];
+					}
+
+					// arguments passed as rest parameter come as one operand //;
+					if ops.len() == 1 {
+						ops = ops.remove(0).into_iter().map(|t| vec![t]).collect();
+					}
+
+					let vl: usize = ops.len();
+					if vl != 2 {
+						make_err!(argcn, s, vl, 2);
+					}
+
+					if let ([ref tok], [ref tok2]) = (ops[0].as_slice(), ops[1].as_slice()) {
+						match (tok, tok2) {
+							(tokenizer::Token::Str(_), tokenizer::Token::Str(_)) => {
+								// VOID //;
+							}
+
+							(tokenizer::Token::Str(_), _) => {
+								make_err!(argt, s, tok2, 2);
+							}
+
+							_ if big_of(tok).is_none() => {
+								make_err!(argt, s, tok, 1);
+							}
+
+							_ if big_of(tok2).is_none() => {
+								make_err!(argt, s, tok2, 2);
+							}
+
+							_ => {
+								// VOID //;
+							}
+						}
+					}
+
+					let o: Ordering = cmp_seqs(&ops[0], &ops[1]);
+					return vec![tokenizer::Token::Bool(match s.as_str() {
+						"<" => o == Ordering::Less,
+						">" => o == Ordering::Greater,
+						"<=" => o != Ordering::Greater,
+						_ => o != Ordering::Less,
+					})];
+				}
+
+				"compare"|"equal?" => {
+					let ops: Vec<Vec<tokenizer::Token>> =
+							eval_operands(&tokens[1..], depth, args, funcs, lambdas, vars, flow);
+
+					if flow.interrupted() {
+						return vec![];
+					}
+
+					let vl: usize = ops.len();
+					if s == "compare" {
+						if vl != 2 {
+							make_err!(argcn, s, vl, 2);
+						}
+
+						let o: i128 = match cmp_seqs(&ops[0], &ops[1]) {
+							Ordering::Less => -1,
+							Ordering::Equal => 0,
+							Ordering::Greater => 1,
+						};
+
+						return vec![big_token(bigint::BigInt::from_i128(o))];
+					}
+
+					if vl == 0 {
+						make_err!(argcf, s, vl, 1);
+					}
+
+					return vec![tokenizer::Token::Bool(
+						ops.iter().all(|v| cmp_seqs(v, &ops[0]) == Ordering::Equal)
+					)];
+				}
+
+				_ => {
+					// not a macro //;
+				}
+			}
+		}
+	}
+
+	for t in tokens.into_iter() {
+		let tmp_t: tokenizer::Token = t.clone();
+		if deep > 0 {
+			match tmp_t {
+				tokenizer::Token::CPair => {
+					deep -= 1;
+				}
+
+				tokenizer::Token::OPair => {
+					deep += 1;
+				}
+
+				_ => {
+					// VOID //;
+				}
+			}
+
+			if deep == 0 {
+				if in_catch {
+					catch_vec.push(stack.clone());
+				} else {
+					if depth == 0 {
+						ret = run_tokens(&stack, depth+1, args, funcs, lambdas, vars, flow);
+					} else {
+						for i in run_tokens(&stack, depth+1, args, funcs, lambdas, vars, flow)
+							.into_iter() {
+							ret.push(i.clone());
+						}
+					}
+
+					if flow.interrupted() {
+						return ret;
+					}
+				}
+				stack.clear();
+			} else {
+				stack.push(tmp_t);
+			}
+		} else if tmp_t == tokenizer::Token::OPair {
+			deep = 1;
+		} else if in_catch {
+			catch_vec.push(vec![tmp_t]);
+		} else {
+			ret.push(tmp_t);
+		}
+	}
+
+	if in_catch {
+		let fun: tokenizer::Token = catch_vec.remove(0).remove(0);
+
+		match fun {
+			tokenizer::Token::Ident(i) => {
+				match i.as_str() {
+					"while" => {
+						let vl: usize = catch_vec.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+						let cond: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+
+						loop {
+							let cond_r: Vec<tokenizer::Token> = run_forced(
+								&cond,
+								depth+1,
+								args,
+								funcs,
+								lambdas,
+								vars,
+								flow
+							);
+
+							if flow.interrupted() {
+								return ret;
+							}
+
+							if !truthy(&cond_r) {
+								break
+							}
+
+							flow.loops += 1;
+							vars.push_scope();
+							let code_r: Vec<tokenizer::Token> = run_tokens(
+								&code,
+								depth+1,
+								args,
+								funcs,
+								lambdas,
+								vars,
+								flow
+							);
+							vars.pop_scope();
+							flow.loops -= 1;
+
+							for i in code_r.into_iter() {
+								ret.push(i);
+							}
+
+							loop_signal!(flow, ret);
+						}
+
+						return ret;
+					}
+
+					"set"|"global-set" => {
+						let vl: usize = catch_vec.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						// stored list should stay the same after it was read //;
+						let res: Vec<tokenizer::Token> = run_tokens(&catch_vec.pop().unwrap(), depth+1, args, funcs, lambdas, vars, flow);
+						let res: Vec<tokenizer::Token> = settle(res, depth, funcs, lambdas, vars, flow);
+
+						
+						let name_t: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+						if let tokenizer::Token::Ident(s) = name_t {
+							let found: bool = if i == "set" {
+								vars.set(s.clone(), res)
+							} else {
+								vars.set_global(s.clone(), res)
+							};
+
+							if !found {
+								make_err!(unknown_ident, s);
+							}
+
+							return vec![];
+						}
+
+						make_err!(argt, i, name_t, 1);
+					}
+
+					"mut"|"global-mut" => {
+						let vl: usize = catch_vec.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						// stored list should stay the same after it was read //;
+						let res: Vec<tokenizer::Token> = run_tokens(&catch_vec.pop().unwrap(), depth+1, args, funcs, lambdas, vars, flow);
+						let res: Vec<tokenizer::Token> = settle(res, depth, funcs, lambdas, vars, flow);
+
+						let name_t: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+						if let tokenizer::Token::Ident(s) = name_t {
+							let defined: bool = if i == "mut" {
+								vars.define(s.clone(), res)
+							} else {
+								vars.define_global(s.clone(), res)
+							};
+
+							if !defined {
+								make_err!(redef, i, s);
+							}
+
+							return vec![];
+						}
+
+						make_err!(argt, i, name_t, 1);
+					}
+
+					"case" => {
+						let vl: usize = catch_vec.len();
+						if vl < 3 {
+							make_err!(argc, i, vl, 3, 0);
+						}
+
+						let val: Vec<tokenizer::Token> =
+								run_forced(&catch_vec.remove(0), depth+1, args, funcs, lambdas, vars, flow);
+
+						for pair in catch_vec.chunks(2) {
+							match pair {
+								[case, code]
+								if run_forced(case, depth+1, args, funcs, lambdas, vars, flow) == val => {
+									return run_tokens(code, depth+1, args, funcs, lambdas, vars, flow);
+								}
+
+								[code] => {
+									return run_tokens(code, depth+1, args, funcs, lambdas, vars, flow);
+								}
+
+								_ => {
+									// not matched //;
+								}
+							}
+						}
+
+						return vec![];
+					}
+					
+					"try" => {
+						let vl: usize = catch_vec.len();
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+
+						let mut handler: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+						let tok: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+
+						unless!(let tokenizer::Token::Ident(_) = tok, {
+							make_err!(argt, i, tok, 2);
+						});
+
+						let res: Vec<tokenizer::Token> =
+								run_tokens(&catch_vec.pop().unwrap(), depth+1, args,
+													 funcs, lambdas, vars, flow);
+
+						unless!(let Signal::Error(_) = flow.signal, {
+							return res;
+						});
+
+						if let Signal::Error(msg) = mem::replace(&mut flow.signal, Signal::Normal) {
+							let mut k: usize = 0;
+							while k < handler.len() {
+								if handler[k] == tok {
+									handler[k] = tokenizer::Token::Str(msg.clone());
+								}
+								k += 1;
+							}
+						}
+
+						return run_tokens(&handler, depth+1, args, funcs, lambdas, vars, flow);
+					}
+
+					"and"|"or" => {
+						let is_and: bool = i == "and";
+						let mut last: Vec<tokenizer::Token> = vec![tokenizer::Token::Bool(is_and)];
+
+						// operand is judged as whole, like condition of `if`,
+						//   so empty one is false //;
+						for code in catch_vec.into_iter() {
+							let v: Vec<tokenizer::Token> = run_forced(&code, depth+1, args, funcs, lambdas, vars, flow);
+							if flow.interrupted() {
+								return vec![];
+							}
+
+							last = if v.is_empty() { vec![tokenizer::Token::Bool(false)] } else { v };
+							if truthy(&last) != is_and {
+								return last;
+							}
+						}
+
+						return last;
+					}
+
+					"cond" => {
+						let vl: usize = catch_vec.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						for pair in catch_vec.chunks(2) {
+							match pair {
+								[cond, code]
+								if truthy(&run_forced(cond, depth+1, args, funcs, lambdas, vars, flow)) => {
+									return run_tokens(code, depth+1, args, funcs, lambdas, vars, flow);
+								}
+
+								[code] => {
+									return run_tokens(code, depth+1, args, funcs, lambdas, vars, flow);
+								}
+
+								_ => {
+									// not matched //;
+								}
+							}
+						}
+
+						return vec![];
+					}
+
+					"if" => {
+						let vl: usize = catch_vec.len();
+						if vl < 2 || vl > 3 {
+							make_err!(argc, i, vl, 2, 3);
+						}
+						
+						let cond: Vec<tokenizer::Token> = run_forced(&catch_vec.remove(0),
+																											depth+1, args, funcs,
+																											lambdas, vars, flow);
+						if truthy(&cond) {
+							return run_tokens(&catch_vec.remove(0), depth+1, args, funcs,
+																lambdas, vars, flow);
+						}
+
+						if catch_vec.len() > 1 {
+							return run_tokens(&catch_vec.remove(1), depth+1, args,
+																funcs, lambdas, vars, flow);
+						}
+
+						return vec![];
+					}
+
+
+					"each" => {
+						let vl: usize = catch_vec.len();
+						
+						if vl != 3 {
 							make_err!(argc, i, vl, 3, 3);
 						}
-						
-						let code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
-						let arr: Vec<tokenizer::Token> =
-								run_tokens(&catch_vec.pop().unwrap(), depth+1, args,
-													 funcs, lambdas, vars);
+						
+						let code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+						let arr: Vec<tokenizer::Token> =
+								run_tokens(&catch_vec.pop().unwrap(), depth+1, args,
+													 funcs, lambdas, vars, flow);
+
+						let tok: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+
+						unless!(let tokenizer::Token::Ident(_) = tok, {
+							make_err!(argt, i, tok, 1);
+						});
+
+						if flow.interrupted() {
+							return ret;
+						}
+
+						// iterators are pulled one element at a time //;
+						let mut pending: std::collections::VecDeque<tokenizer::Token> = arr.into();
+						let mut lazy: Option<iter::Lazy> = None;
+						loop {
+							let i: tokenizer::Token;
+							if let Some(it) = lazy.clone() {
+								match iter_next(&it, depth, funcs, lambdas, vars, flow) {
+									Some(t) => {
+										i = t;
+									}
+
+									None => {
+										if flow.interrupted() {
+											return ret;
+										}
+
+										lazy = None;
+										continue
+									}
+								}
+							} else {
+								match pending.pop_front() {
+									Some(tokenizer::Token::Iter(it)) => {
+										lazy = Some(it);
+										continue
+									}
+
+									Some(t) => {
+										i = t;
+									}
+
+									None => {
+										break
+									}
+								}
+							}
+
+							let mut tmp: Vec<tokenizer::Token> = code.clone();
+							let mut k: usize = 0;
+							while k < tmp.len() {
+								if tmp[k] == tok {
+									tmp[k] = i.clone();
+								}
+								k += 1;
+							}
+							
+							flow.loops += 1;
+							vars.push_scope();
+							for j in run_tokens(&tmp, depth+1, args, funcs, lambdas, vars, flow)
+								.into_iter() {
+								ret.push(j);
+							}
+							vars.pop_scope();
+							flow.loops -= 1;
+
+							loop_signal!(flow, ret);
+						}
+
+						return ret;
+					}
+
+					"bind" => {
+						let vl: usize = catch_vec.len();
+						
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+						
+						//let ident: String;
+						
+						let mut code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+						// generators and other single pass iterators are kept as handles //;
+						let val: Vec<tokenizer::Token> =
+								run_tokens(&catch_vec.pop().unwrap(), depth+1, args,
+													 funcs, lambdas, vars, flow);
+						let val: Vec<tokenizer::Token> = settle(val, depth, funcs, lambdas, vars, flow);
+
+						let tok: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+
+						unless!(let tokenizer::Token::Ident(_) = tok, {
+							make_err!(argt, i, tok, 1);
+						});
+
+						let fval: Vec<tokenizer::Token> =
+								run_tokens(&val, depth+1, args, funcs, lambdas, vars, flow);
+
+						let mut k: usize = 0;
+						while k < code.len() {
+							if code[k] == tok {
+								code.remove(k);
+								for (index, t) in fval.clone().into_iter().enumerate() {
+									code.insert(k+index, t);
+								}
+							}
+							k += 1;
+						}
+
+						vars.push_scope();
+						let res: Vec<tokenizer::Token> =
+								run_tokens(&code, depth+1, args, funcs, lambdas, vars, flow);
+						vars.pop_scope();
+
+						return res;
+					}
+					
+					"let" => {
+						let vl: usize = catch_vec.len();
+						if vl < 2  {
+							make_err!(argcf, i, vl, 2);
+						}
+
+						let mut code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
+						let funn: tokenizer::Token = catch_vec.remove(0).remove(0);
+
+						if let tokenizer::Token::Ident(fi) = funn {
+							if funcs.contains_key(&fi) {
+								make_err!(redef, i, fi);
+							}
+
+							let amp: tokenizer::Token = tokenizer::Token::Ident(String::from("&"));
+							let new_style: bool = catch_vec.iter().any(|p| p.len() > 1 || p.first() == Some(&amp));
+
+							// parameters in order of their slots //;
+							let mut names: Vec<tokenizer::Token> = Vec::new();
+							let mut rest: Option<tokenizer::Token> = None;
+							let mut sig: Option<func::Sig> = None;
+
+							if new_style {
+								let mut sg: func::Sig = func::Sig {
+									positional: 0,
+									required: 0,
+									keywords: Vec::new(),
+									defaults: Vec::new(),
+									rest: false,
+									show: String::new(),
+								};
+
+								let mut kw_names: Vec<tokenizer::Token> = Vec::new();
+								let mut kw_defaults: Vec<Option<Vec<tokenizer::Token>>> = Vec::new();
+								let mut shows: Vec<String> = Vec::new();
+								let mut after_amp: bool = false;
+
+								for (index, mut p) in catch_vec.into_iter().enumerate() {
+									let name: tokenizer::Token = p.remove(0);
+									let ns: String = match name {
+										tokenizer::Token::Ident(ref ns) => ns.clone(),
+										_ => {
+											make_err!(argt, i, name, index+2);
+										}
+									};
+
+									if after_amp {
+										if !p.is_empty() || sg.rest {
+											make_err!(value, i, name, "single rest parameter after `&`");
+										}
+
+										shows.push(format!("& {}", ns));
+										sg.rest = true;
+										rest = Some(name);
+									} else if ns == "&" {
+										if !p.is_empty() {
+											make_err!(value, i, name, "`&` without default");
+										}
+
+										after_amp = true;
+									} else if p.is_empty() {
+										if sg.positional > sg.required {
+											make_err!(value, i, name, "required parameters before optional ones");
+										}
+
+										shows.push(ns);
+										names.push(name);
+										sg.positional += 1;
+										sg.required += 1;
+										sg.defaults.push(None);
+									} else if ns.starts_with(':') && ns.len() > 1 {
+										shows.push(format!("[{}]", ns));
+										kw_names.push(tokenizer::Token::Ident(ns[1..].to_string()));
+										sg.keywords.push(ns[1..].to_string());
+										kw_defaults.push(Some(p));
+									} else {
+										shows.push(format!("[{}]", ns));
+										names.push(name);
+										sg.positional += 1;
+										sg.defaults.push(Some(p));
+									}
+								}
+
+								names.extend(kw_names);
+								sg.defaults.extend(kw_defaults);
+								for (index, d) in sg.defaults.iter_mut().enumerate() {
+									if let Some(code) = d {
+										for (j, tok) in names[..index].iter().enumerate() {
+											substitute(code, tok, &[
+												tokenizer::Token::OPair,
+												tokenizer::Token::Ident(String::from("%")),
+												tokenizer::Token::Digit(j as u128),
+												tokenizer::Token::CPair,
+											]);
+										}
+									}
+								}
+
+								sg.show = shows.join(" ");
+								sig = Some(sg);
+							} else if vl > 2 {
+								let tok: tokenizer::Token =
+										catch_vec.pop().unwrap().remove(0);
+
+								unless!(let tokenizer::Token::Ident(_) = tok, {
+									make_err!(argt, i, tok, "rest");
+								});
+
+								rest = Some(tok);
+								for (index, mut p) in catch_vec.into_iter().enumerate() {
+									let tok_arg: tokenizer::Token = p.remove(0);
+									unless!(let tokenizer::Token::Ident(_) = tok_arg, {
+										make_err!(argt, i, tok_arg, index+1);
+									});
+
+									names.push(tok_arg);
+								}
+
+								// old style: parameters are required, last one takes the rest //;
+								let mut shows: Vec<String> = Vec::new();
+								for t in names.iter().chain(rest.iter()) {
+									if let tokenizer::Token::Ident(ns) = t {
+										shows.push(ns.clone());
+									}
+								}
+
+								shows.insert(names.len(), String::from("&"));
+								sig = Some(func::Sig {
+									positional: names.len(),
+									required: names.len(),
+									keywords: Vec::new(),
+									defaults: vec![None; names.len()],
+									rest: true,
+									show: shows.join(" "),
+								});
+							}
+
+							if let Some(tok) = rest {
+								let mut rest_toks: Vec<tokenizer::Token> = Vec::new();
+								for _ in 0..names.len() {
+									rest_toks.push(tokenizer::Token::OPair);
+									rest_toks.push(tokenizer::Token::Ident(String::from("rm")));
+									rest_toks.push(tokenizer::Token::Digit(0));
+								}
+
+								rest_toks.push(tokenizer::Token::OPair);
+								rest_toks.push(tokenizer::Token::Ident(String::from("%%")));
+								rest_toks.push(tokenizer::Token::CPair);
+
+								for _ in 0..names.len() {
+									rest_toks.push(tokenizer::Token::CPair);
+								}
+
+								substitute(&mut code, &tok, &rest_toks);
+							}
+
+							for (index, tok) in names.iter().enumerate().rev() {
+								substitute(&mut code, tok, &[
+									tokenizer::Token::OPair,
+									tokenizer::Token::Ident(String::from("%")),
+									tokenizer::Token::Digit(index as u128),
+									tokenizer::Token::CPair,
+								]);
+							}
+
+							funcs.insert(fi.clone(), func::Func {
+								code,
+								sig,
+								methods: Vec::new(),
+							});
+							return vec![];
+						}
+
+						make_err!(argt, i, funn, 1);
+					}
+
+					_ => {
+						unreachable!("what 2");
+					}
+				}
+			}
+
+			_ => {
+				unreachable!("what 1");
+			}
+		}
+	}
+
+	if depth > 0 && ret.len() > 0 {
+		let fun: tokenizer::Token = ret.remove(0);
+		match fun {
+			tokenizer::Token::Ident(i) => {
+				match i.as_str() {
+					"take"|"skip"|"next"|"resume"|"send"|"iter?"|"map"|"flat-map"|"filter"|"take-while"|"drop-while"|
+					"enumerate"|"return"|"break" => {
+						// iterator-aware, lazy arguments are left as is //;
+					}
+
+					_ => {
+						ret = force(ret, depth, funcs, lambdas, vars, flow);
+						if flow.interrupted() {
+							return vec![];
+						}
+					}
+				}
+
+				match i.as_str() {
+					"as-int" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let t = ret.pop().unwrap();
+						if let tokenizer::Token::Str(ref s) = t {
+							let mut cs = s.chars();
+							if let (Some(c), None) = (cs.next(), cs.next()) {
+								return vec![tokenizer::Token::Digit(c as u128)];
+							} else {
+								make_err!(value, i, t, "single char");
+							}
+						} else {
+							make_err!(argt, i, t, 1);
+						}
+					}
+
+					"as-str" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, 0, 1);
+						}
+
+						let mut st: String = String::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							if let tokenizer::Token::Digit(d) = t {
+								if let Some(c) = char_of(d) {
+									st.push(c);
+									continue
+								}
+
+								make_err!(value, i, t, "unicode scalar value");
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						return vec![tokenizer::Token::Str(st)];
+					}
+
+					"as-char" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let t = ret.pop().unwrap();
+						if let tokenizer::Token::Digit(d) = t {
+							if let Some(c) = char_of(d) {
+								return vec![tokenizer::Token::Str(String::from(c))];
+							}
+
+							make_err!(value, i, t, "unicode scalar value");
+						} else {
+							make_err!(argt, i, t, 1);
+						}
+					}
+
+					"str-from-bytes" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, 0, 1);
+						}
+
+						let mut tmp: Vec<u8> = Vec::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							if let tokenizer::Token::Digit(b) = t {
+								if b < 256 {
+									tmp.push(b as u8);
+									continue
+								}
+
+								make_err!(value, i, t, "byte");
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						return vec![
+							tokenizer::Token::Str(String::from_utf8_lossy(tmp.as_slice())
+								.to_string())
+						];
+					}
+
+					"byte-len" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(s) = tok {
+							return vec![tokenizer::Token::Digit(s.len() as u128)];
+						}
+
+						make_err!(argt, i, tok, 1);
+					}
+
+					"graphemes"|"grapheme-len" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(s) = tok {
+							let gs: Vec<String> = grapheme::graphemes(&s);
+							if i == "grapheme-len" {
+								return vec![tokenizer::Token::Digit(gs.len() as u128)];
+							}
+
+							for g in gs.into_iter() {
+								ret.push(tokenizer::Token::Str(g));
+							}
+
+							return ret;
+						}
+
+						make_err!(argt, i, tok, 1);
+					}
+
+					"grapheme-slice" => {
+						let vl: usize = ret.len();
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						let etok: tokenizer::Token = ret.pop().unwrap();
+						let stok: tokenizer::Token = ret.pop().unwrap();
+
+						if let tokenizer::Token::Str(s) = tok {
+							if let tokenizer::Token::Digit(start) = stok {
+								if let tokenizer::Token::Digit(end) = etok {
+									let gs: Vec<String> = grapheme::graphemes(&s);
+									// clamped before narrowing, so huge bounds don't wrap //;
+									let end: usize = end.min(gs.len() as u128) as usize;
+									let start: usize = start.min(end as u128) as usize;
+									return vec![tokenizer::Token::Str(gs[start..end].concat())];
+								}
+
+								make_err!(argt, i, etok, 2);
+							}
+
+							make_err!(argt, i, stok, 1);
+						}
+
+						make_err!(argt, i, tok, 3);
+					}
+
+					"get-env" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(s) = tok {
+							return vec![tokenizer::Token::Str(
+								if let Ok(r) = env::var(s) {
+									r
+								} else {
+									String::new()
+								}
+							)];
+						} else {
+							make_err!(argt, i, tok, 1);
+						}
+					}
+
+					"input" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, 0, 1);
+						}
+
+						let mut tmp: Vec<tokenizer::Token> = Vec::new();
+						mem::swap(&mut ret, &mut tmp);
+
+						let stdin = io::stdin();
+						let mut stdout = io::stdout();
+						for (index, t) in tmp.into_iter().enumerate() {
+							if let tokenizer::Token::Str(s) = t {
+								print!("{}", s);
+								stdout.flush().expect("stdout flush failed");
+								let mut rs: String = String::new();
+								stdin.read_line(&mut rs).expect("stdin read_line failed");
+								ret.push(tokenizer::Token::Str(rs.trim().to_string()));
+								continue
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						return ret;
+					}
+
+					"file-read" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, 0, 1);
+						}
+						
+						let mut tmp: Vec<tokenizer::Token> = Vec::new();
+						mem::swap(&mut ret, &mut tmp);
+
+						for (index, t) in tmp.into_iter().enumerate() {
+							if let tokenizer::Token::Str(s) = t {
+								ret.push(
+									tokenizer::Token::Str(
+										fs::read_to_string(s.as_str())
+											.expect("failed to read file")));
+
+								continue
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						return ret;
+					}
+
+					"file-write" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let fc: tokenizer::Token = ret.pop().unwrap();
+						let fl: tokenizer::Token = ret.pop().unwrap();
+
+						if let tokenizer::Token::Str(fls) = fl {
+							if let tokenizer::Token::Str(fcs) = fc {
+								fs::write(fls.as_str(), fcs.as_str())
+									.expect("failed to write file");
+
+								return vec![tokenizer::Token::Bool(true)];
+							}
+							
+							make_err!(argt, i, fc, 2);	
+						}
+					
+						make_err!(argt, i, fl, 1);	
+					}
+					
+					"int?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						return vec![tokenizer::Token::Bool(matches!(ret[0], tokenizer::Token::Digit(_)|tokenizer::Token::Big(_)))];
+					}
+
+					"str?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						if let tokenizer::Token::Str(_) = ret.pop().unwrap() {
+							return vec![tokenizer::Token::Bool(true)];
+						}
+						
+						return vec![tokenizer::Token::Bool(false)];
+					}
+
+					"nil?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						if let tokenizer::Token::Nil = ret.pop().unwrap() {
+							return vec![tokenizer::Token::Bool(true)];
+						}
+						
+						return vec![tokenizer::Token::Bool(false)];
+					}
+
+					"bool?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						if let tokenizer::Token::Bool(_) = ret.pop().unwrap() {
+							return vec![tokenizer::Token::Bool(true)];
+						}
+						
+						return vec![tokenizer::Token::Bool(false)];
+					}
+
+					"vec" => {
+						return vec![tokenizer::Token::Vector(vector::Vector::new(&ret))];
+					}
+
+					"vec?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						if let tokenizer::Token::Vector(_) = ret.pop().unwrap() {
+							return vec![tokenizer::Token::Bool(true)];
+						}
+
+						return vec![tokenizer::Token::Bool(false)];
+					}
+
+					"vec-len"|"vec-items"|"vec-pop"|"vec-pop-front" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let v: vector::Vector = match ret.pop().unwrap() {
+							tokenizer::Token::Vector(v) => v,
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						let n: usize = v.len();
+						return match i.as_str() {
+							"vec-len" => vec![tokenizer::Token::Digit(n as u128)],
+							"vec-items" => v.items(),
+							"vec-pop" => vec![tokenizer::Token::Vector(v.slice(0, n.saturating_sub(1)))],
+							_ => vec![tokenizer::Token::Vector(v.slice(n.min(1), n))],
+						};
+					}
+
+					// element or nil, like `nth` //;
+					"vec-get" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						if let (tokenizer::Token::Vector(v), tokenizer::Token::Digit(d)) = (&ret[0], &ret[1]) {
+							if *d >= v.len() as u128 {
+								return vec![tokenizer::Token::Nil];
+							}
+
+							return vec![v.get(*d as usize).unwrap().clone()];
+						}
+
+						let index: usize = if let tokenizer::Token::Vector(_) = ret[0] { 2 } else { 1 };
+						let t: tokenizer::Token = ret.remove(index-1);
+						make_err!(argt, i, t, index);
+					}
+
+					// (vec-set v i x) and (vec-update v i f) give vector with changed element //;
+					"vec-set"|"vec-update" => {
+						let vl: usize = ret.len();
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+
+						let x: tokenizer::Token = ret.pop().unwrap();
+						let (v, d): (vector::Vector, u128) = match (ret.remove(0), ret.remove(0)) {
+							(tokenizer::Token::Vector(v), tokenizer::Token::Digit(d)) => (v, d),
+							(tokenizer::Token::Vector(_), t) => {
+								make_err!(argt, i, t, 2);
+							}
+
+							(t, _) => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						if d >= v.len() as u128 {
+							make_err!(indexerr, i, d, v.len());
+						}
+
+						let x: tokenizer::Token = if i == "vec-update" {
+							let old: tokenizer::Token = v.get(d as usize).unwrap().clone();
+							let res: Vec<tokenizer::Token> = call(&x, vec![old], depth, funcs, lambdas, vars, flow);
+							if flow.interrupted() {
+								return vec![];
+							}
+
+							res.into_iter().next().unwrap_or(tokenizer::Token::Nil)
+						} else {
+							x
+						};
+
+						return vec![tokenizer::Token::Vector(v.set(d as usize, x).unwrap())];
+					}
+
+					"vec-push"|"vec-push-front" => {
+						let vl: usize = ret.len();
+						if vl < 1 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						let mut v: vector::Vector = match ret.remove(0) {
+							tokenizer::Token::Vector(v) => v,
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						if i == "vec-push" {
+							for t in ret.into_iter() {
+								v = v.push(t);
+							}
+						} else {
+							for t in ret.into_iter().rev() {
+								v = v.push_front(t);
+							}
+						}
+
+						return vec![tokenizer::Token::Vector(v)];
+					}
+
+					// (vec-slice v from to), to is not included and is end of vector by default //;
+					"vec-slice" => {
+						let vl: usize = ret.len();
+						if vl != 2 && vl != 3 {
+							make_err!(argc, i, vl, 2, 3);
+						}
+
+						let v: vector::Vector = match ret.remove(0) {
+							tokenizer::Token::Vector(v) => v,
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						let mut bounds: Vec<usize> = Vec::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							match t {
+								tokenizer::Token::Digit(d) if d <= v.len() as u128 => bounds.push(d as usize),
+								tokenizer::Token::Digit(d) => {
+									make_err!(indexerr, i, d, v.len()+1);
+								}
+
+								_ => {
+									make_err!(argt, i, t, index+2);
+								}
+							}
+						}
+
+						let to: usize = bounds.get(1).cloned().unwrap_or(v.len());
+						return vec![tokenizer::Token::Vector(v.slice(bounds[0], to.max(bounds[0])))];
+					}
+
+					"vec-concat" => {
+						let mut res: vector::Vector = vector::Vector::new(&[]);
+						for (index, t) in ret.into_iter().enumerate() {
+							match t {
+								tokenizer::Token::Vector(v) => res = res.concat(&v),
+								_ => {
+									make_err!(argt, i, t, index+1);
+								}
+							}
+						}
+
+						return vec![tokenizer::Token::Vector(res)];
+					}
+
+					"set-of" => {
+						return vec![tokenizer::Token::Set(set::Set::new(ret))];
+					}
+
+					"set?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						if let tokenizer::Token::Set(_) = ret.pop().unwrap() {
+							return vec![tokenizer::Token::Bool(true)];
+						}
+
+						return vec![tokenizer::Token::Bool(false)];
+					}
+
+					"set-len"|"set-items" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						return match ret.pop().unwrap() {
+							tokenizer::Token::Set(st) if i == "set-len" => vec![tokenizer::Token::Digit(st.len() as u128)],
+							tokenizer::Token::Set(st) => st.items(),
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+					}
+
+					// (set-add s items...), (set-remove s items...) and (set-has? s items...) //;
+					"set-add"|"set-remove"|"set-has?" => {
+						let vl: usize = ret.len();
+						if vl < 2 {
+							make_err!(argcf, i, vl, 2);
+						}
+
+						let mut st: set::Set = match ret.remove(0) {
+							tokenizer::Token::Set(st) => st,
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						match i.as_str() {
+							"set-has?" => {
+								return vec![tokenizer::Token::Bool(ret.iter().all(|t| st.contains(t)))];
+							}
+
+							"set-add" => {
+								for t in ret.into_iter() {
+									st.insert(t);
+								}
+							}
+
+							_ => {
+								for t in ret.iter() {
+									st.remove(t);
+								}
+							}
+						}
+
+						return vec![tokenizer::Token::Set(st)];
+					}
+
+					// (set-diff s others...) is elements of s, which are not in others //;
+					"set-union"|"set-intersect"|"set-diff"|"set-subset?" => {
+						let vl: usize = ret.len();
+						if vl < 1 || (i == "set-subset?" && vl != 2) {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let mut sets: Vec<set::Set> = Vec::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							match t {
+								tokenizer::Token::Set(st) => sets.push(st),
+								_ => {
+									make_err!(argt, i, t, index+1);
+								}
+							}
+						}
+
+						let first: set::Set = sets.remove(0);
+						if i == "set-subset?" {
+							return vec![tokenizer::Token::Bool(first.is_subset(&sets[0]))];
+						}
+
+						let res: set::Set = sets.iter().fold(first, |acc, st| match i.as_str() {
+							"set-union" => acc.union(st),
+							"set-intersect" => acc.intersection(st),
+							_ => acc.difference(st),
+						});
+
+						return vec![tokenizer::Token::Set(res)];
+					}
+
+					"type-of" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						return vec![tokenizer::Token::Str(type_names(&ret[0]).remove(0))];
+					}
+
+					// first method found wins, types of left arguments are more
+					//   significant, so (int any) is chosen before (any int) //;
+					"call-method" => {
+						let vl: usize = ret.len();
+						if vl < 1 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						let name: String = match ret.remove(0) {
+							tokenizer::Token::Str(n) => n,
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						// every method of generic is checked once, it applies when its
+						//   signature accepts arguments and its types fit their positional part
+						// rank of method is position of its types in lists of argument types,
+						//   compared from the leftmost argument, the smallest wins //;
+						let methods: Vec<Vec<String>> = funcs.get(&name).map_or(Vec::new(), |f| f.methods.clone());
+						let mut best: Option<(Vec<usize>, String)> = None;
+
+						for types in methods.iter() {
+							if *types == ["default"] {
+								continue
+							}
+
+							let mname: String = method_name(&name, types);
+							let sig: &func::Sig = match funcs.get(&mname).and_then(|f| f.sig.as_ref()) {
+								Some(sig) => sig,
+								None => continue,
+							};
+
+							let given: Vec<tokenizer::Token> = match sig.arrange(ret.clone()) {
+								Ok((slots, rest)) => slots.into_iter().take(sig.positional).flatten().chain(rest).collect(),
+								Err(_) => continue,
+							};
+
+							if given.len() < types.len() {
+								continue
+							}
+
+							let rank: Option<Vec<usize>> = types.iter().zip(given.iter())
+								.map(|(d, t)| type_names(t).iter().position(|n| n == d))
+								.collect();
+
+							if let Some(rank) = rank {
+								if best.as_ref().is_none_or(|b| rank < b.0) {
+									best = Some((rank, mname));
+								}
+							}
+						}
+
+						let choices: Vec<Vec<String>> = ret.iter().map(type_names).collect();
+						let mut found: Option<String> = best.map(|b| b.1);
+						if found.is_none() {
+							let mname: String = method_name(&name, &[String::from("default")]);
+							if funcs.contains_key(&mname) {
+								found = Some(mname);
+							}
+						}
+
+						match found {
+							Some(mname) => {
+								return call(&tokenizer::Token::Ident(mname), ret, depth, funcs, lambdas, vars, flow);
+							}
+
+							None => {
+								let types: Vec<String> = choices.into_iter().map(|mut c| c.remove(0)).collect();
+								raise!(flow, "no method of `{}` for ({})", name, types.join(" "));
+							}
+						}
+					}
+
+					"ident?" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						if let tokenizer::Token::Ident(_) = ret.pop().unwrap() {
+							return vec![tokenizer::Token::Bool(true)];
+						}
+						
+						return vec![tokenizer::Token::Bool(false)];
+					}
+
+					"ident-name" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+
+						if let tokenizer::Token::Ident(s) = tok {
+							return vec![tokenizer::Token::Str(s)];
+						} else {
+							make_err!(argt, i, tok, 1);
+						}
+					}
+
+					"ident-addr" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						
+						if let tokenizer::Token::Ident(s) = tok {
+							return vec![tokenizer::Token::Str(
+								if let Some(val) = funcs.get(&s) {
+									format!("{:p}", val.code.as_ptr())
+								} else if let Some(val) = vars.get(&s) {
+									format!("{:p}", val.as_ptr())
+								} else {
+									String::from("0x0")
+								}
+							)];
+						} else {
+							make_err!(argt, i, tok, 1);
+						}
+					}
+
+					"defstruct" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						let mut names: Vec<String> = Vec::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							if let tokenizer::Token::Ident(n) = t {
+								names.push(n);
+								continue
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						let name: String = names.remove(0);
+						for (fname, f) in struct_funcs(&name, &names, None).into_iter() {
+							if funcs.contains_key(&fname) {
+								make_err!(redef, i, fname);
+							}
+
+							funcs.insert(fname, f);
+						}
+
+						return vec![];
+					}
+
+					"record-new" => {
+						let vl: usize = ret.len();
+						if vl < 2 {
+							make_err!(argcf, i, vl, 2);
+						}
+
+						let name: tokenizer::Token = ret.remove(0);
+						let ntok: tokenizer::Token = ret.remove(0);
+						let n: usize = match ntok {
+							// compared in u128, so huge count can't wrap around //;
+							tokenizer::Token::Digit(d) if d.checked_mul(2) == Some(ret.len() as u128) => d as usize,
+							_ => {
+								make_err!(value, i, ntok, "count of field names and values");
+							}
+						};
+
+						let values: Vec<tokenizer::Token> = ret.split_off(n);
+						let mut fields: Vec<(String, tokenizer::Token)> = Vec::new();
+						for (index, (f, v)) in ret.into_iter().zip(values).enumerate() {
+							if let tokenizer::Token::Str(fs) = f {
+								fields.push((fs, v));
+								continue
+							}
+
+							make_err!(argt, i, f, index+3);
+						}
+
+						if let tokenizer::Token::Str(name) = name {
+							return vec![tokenizer::Token::Record(Arc::new(record::Record {
+								name,
+								fields,
+								variant: None,
+							}))];
+						}
+
+						make_err!(argt, i, name, 1);
+					}
+
+					"record-variant" => {
+						let vl: usize = ret.len();
+						if vl < 2 {
+							make_err!(argcf, i, vl, 2);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						let mut names: Vec<String> = Vec::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							if let tokenizer::Token::Str(n) = t {
+								names.push(n);
+								continue
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
 
-						let tok: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+						if let tokenizer::Token::Record(r) = tok {
+							let mut rec: record::Record = (*r).clone();
+							rec.variant = Some(Arc::new(record::Variant {
+								name: names.remove(0),
+								cases: names,
+							}));
 
-						unless!(let tokenizer::Token::Ident(_) = tok, {
-							make_err!(argt, i, tok, 1);
-						});
+							return vec![tokenizer::Token::Record(Arc::new(rec))];
+						}
 
-						for i in arr.into_iter() {
-							let mut tmp: Vec<tokenizer::Token> = code.clone();
-							let mut k: usize = 0;
-							while k < tmp.len() {
-								if tmp[k] == tok {
-									tmp[k] = i.clone();
+						make_err!(argt, i, tok, vl);
+					}
+
+					"record-variant?" => {
+						let vl: usize = ret.len();
+						if vl < 2 {
+							make_err!(argcf, i, vl, 2);
+						}
+
+						let name: tokenizer::Token = ret.remove(0);
+						return vec![tokenizer::Token::Bool(ret.iter().all(|t| match (t, &name) {
+							(tokenizer::Token::Record(r), tokenizer::Token::Str(n)) =>
+								r.variant.as_ref().is_some_and(|v| v.name == *n),
+							_ => false,
+						}))];
+					}
+
+					"record-is?"|"record?" => {
+						let vl: usize = ret.len();
+						let min: usize = if i == "record?" { 1 } else { 2 };
+						if vl < min {
+							make_err!(argcf, i, vl, min);
+						}
+
+						let name: Option<tokenizer::Token> = if i == "record?" { None } else { Some(ret.remove(0)) };
+						return vec![tokenizer::Token::Bool(ret.iter().all(|t| match (t, &name) {
+							(tokenizer::Token::Record(r), Some(tokenizer::Token::Str(n))) => r.name == *n,
+							(tokenizer::Token::Record(_), None) => true,
+							_ => false,
+						}))];
+					}
+
+					"record-get" => {
+						let vl: usize = ret.len();
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						let field: tokenizer::Token = ret.pop().unwrap();
+						let name: tokenizer::Token = ret.pop().unwrap();
+						if let (tokenizer::Token::Record(ref r), tokenizer::Token::Str(ref n), tokenizer::Token::Str(ref f)) =
+								(&tok, &name, &field) {
+							if r.name == *n {
+								if let Some(v) = r.get(f) {
+									return vec![v.clone()];
 								}
-								k += 1;
 							}
-							
-							for j in run_tokens(&tmp, depth+1, args, funcs, lambdas, vars)
-								.into_iter() {
-								ret.push(j);
+						}
+
+						make_err!(value, i, tok, format!("record of type `{}` with field `{}`",
+																						 tokenizer::token_show(&name),
+																						 tokenizer::token_show(&field)));
+					}
+
+					"record-with" => {
+						let vl: usize = ret.len();
+						if vl < 2 {
+							make_err!(argcf, i, vl, 2);
+						}
+
+						let name: tokenizer::Token = ret.remove(0);
+						let tok: tokenizer::Token = ret.remove(0);
+						let mut rec: record::Record = match (&tok, &name) {
+							(tokenizer::Token::Record(r), tokenizer::Token::Str(n)) if r.name == *n => (**r).clone(),
+							_ => {
+								make_err!(value, i, tok, format!("record of type {}", tokenizer::token_show(&name)));
+							}
+						};
+
+						let mut it = ret.into_iter();
+						while let Some(k) = it.next() {
+							let field: Option<String> = match k {
+								tokenizer::Token::Ident(ref s) if s.starts_with(':') => Some(s[1..].to_string()),
+								_ => None,
+							};
+
+							let slot: Option<&mut (String, tokenizer::Token)> = match field {
+								Some(ref f) => rec.fields.iter_mut().find(|p| p.0 == *f),
+								None => None,
+							};
+
+							match (slot, it.next()) {
+								(Some(p), Some(v)) => {
+									p.1 = v;
+								}
+
+								_ => {
+									make_err!(value, i, k, format!(":field and value for `{}`", rec.name));
+								}
 							}
 						}
 
-						return ret;
+						return vec![tokenizer::Token::Record(Arc::new(rec))];
 					}
 
-					"bind" => {
-						let vl: usize = catch_vec.len();
-						
-						if vl != 3 {
-							make_err!(argcn, i, vl, 3);
+					"intern" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
 						}
-						
-						//let ident: String;
-						
-						let mut code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
-						let val: Vec<tokenizer::Token> =
-								run_tokens(&catch_vec.pop().unwrap(), depth+1, args,
-													 funcs, lambdas, vars);
 
-						let tok: tokenizer::Token = catch_vec.pop().unwrap().remove(0);
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(s) = tok {
+							return vec![tokenizer::Token::Ident(s)];
+						}
 
-						unless!(let tokenizer::Token::Ident(_) = tok, {
-							make_err!(argt, i, tok, 1);
-						});
+						make_err!(argt, i, tok, 1);
+					}
 
-						let fval: Vec<tokenizer::Token> =
-								run_tokens(&val, depth+1, args, funcs, lambdas, vars);
+					// (funcall f args...) passes args as they are, (apply f args... list)
+					//   also spreads its last argument, when it's vector, set or iterator //;
+					"apply"|"funcall" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
 
-						let mut k: usize = 0;
-						while k < code.len() {
-							if code[k] == tok {
-								code.remove(k);
-								for (index, t) in fval.clone().into_iter().enumerate() {
-									code.insert(k+index, t);
+						let f: tokenizer::Token = match ret.remove(0) {
+							tokenizer::Token::Str(s) => tokenizer::Token::Ident(s),
+							tokenizer::Token::Ident(s) => tokenizer::Token::Ident(s),
+							t => {
+								make_err!(argt, i, t, 1);
+							}
+						};
+
+						if i == "apply" {
+							match ret.pop() {
+								Some(tokenizer::Token::Vector(v)) => ret.extend(v.items()),
+								Some(tokenizer::Token::Set(st)) => ret.extend(st.items()),
+								Some(t @ tokenizer::Token::Iter(_)) => {
+									let items: Vec<tokenizer::Token> = force(vec![t], depth, funcs, lambdas, vars, flow);
+									ret.extend(items);
 								}
+
+								Some(t) => ret.push(t),
+								None => {}
 							}
-							k += 1;
 						}
 
-						return run_tokens(&code, depth+1, args, funcs, lambdas, vars);
+						return call(&f, ret, depth, funcs, lambdas, vars, flow);
+					}
+
+					"compose"|"partial" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						for (index, t) in ret.iter().enumerate() {
+							if i == "partial" && index > 0 {
+								break
+							}
+
+							unless!(let tokenizer::Token::Ident(_) = t, {
+								make_err!(argt, i, t, index+1);
+							});
+						}
+
+						// (compose f g) is (f (g (%%))), (partial f a) is (f a (%%)) //;
+						let mut code: Vec<tokenizer::Token> = Vec::new();
+						let n: usize = ret.len();
+						if i == "compose" {
+							for f in ret.into_iter() {
+								code.push(f);
+								code.push(tokenizer::Token::OPair);
+							}
+						} else {
+							code.extend(ret);
+							code.push(tokenizer::Token::OPair);
+						}
+
+						code.push(tokenizer::Token::Ident(String::from("%%")));
+						for _ in 0..(if i == "compose" { n } else { 1 }) {
+							code.push(tokenizer::Token::CPair);
+						}
+
+						let name: String = format!("{}l", *lambdas);
+						*lambdas += 1;
+						funcs.insert(name.clone(), func::Func::new(code));
+						return vec![tokenizer::Token::Ident(name)];
+					}
+
+					"bytes" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(s) = tok {
+							return vec![tokenizer::Token::Iter(iter::Lazy::new(
+								iter::Source::Bytes { s, pos: 0 }
+							))];
+						}
+
+						make_err!(argt, i, tok, 1);
+					}
+
+					"chars" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(s) = tok {
+							return vec![tokenizer::Token::Iter(iter::Lazy::new(
+								iter::Source::Chars { s, pos: 0 }
+							))];
+						}
+						
+						make_err!(argt, i, tok, 1);
+					}
+
+					"str-collect" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						let mut s: String = String::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							if let tokenizer::Token::Str(ref ts) = t {
+								s += ts;
+								continue
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						return vec![tokenizer::Token::Str(s)];
 					}
 					
-					"let" => {
-						let vl: usize = catch_vec.len();
-						if vl < 2  {
-							make_err!(argcf, i, vl, 2);
+					"format" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
 						}
 
-						let mut code: Vec<tokenizer::Token> = catch_vec.pop().unwrap();
-						let funn: tokenizer::Token = catch_vec.remove(0).remove(0);
+						let ftok: tokenizer::Token = ret.remove(0);
+						if let tokenizer::Token::Str(f) = ftok {
+							match format::format(&f, &ret, vars) {
+								Ok(s) => {
+									return vec![tokenizer::Token::Str(s)];
+								}
 
-						if let tokenizer::Token::Ident(fi) = funn {
-							if funcs.contains_key(&fi) {
-								make_err!(redef, i, fi);
+								Err(e) => {
+									raise!(flow, "`{}`: {}", i, e);
+								}
+							}
+						}
+
+						make_err!(argt, i, ftok, 1);
+					}
+
+					// argument of `format` made by f-string //;
+					"format-arg" => {
+						if let [tokenizer::Token::Ident(ref name)] = ret.as_slice() {
+							if let Some(val) = vars.get(name) {
+								ret = val.clone();
+							}
+						}
+
+						match format::group(&ret) {
+							Ok(t) => {
+								return vec![t];
+							}
+
+							Err(e) => {
+								raise!(flow, "`{}`: {}", i, e);
+							}
+						}
+					}
+
+					"str-split" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let a: Vec<String> = str_args(&i, ret, 1);
+						if a[0].is_empty() {
+							return a[1].chars().map(|c| tokenizer::Token::Str(String::from(c))).collect();
+						}
+
+						return a[1].split(a[0].as_str())
+							.map(|p| tokenizer::Token::Str(String::from(p)))
+							.collect();
+					}
+
+					"str-join" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						let a: Vec<String> = str_args(&i, ret, 1);
+						return vec![tokenizer::Token::Str(a[1..].join(a[0].as_str()))];
+					}
+
+					"substr" => {
+						let vl: usize = ret.len();
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						let etok: tokenizer::Token = ret.pop().unwrap();
+						let stok: tokenizer::Token = ret.pop().unwrap();
+
+						if let tokenizer::Token::Str(s) = tok {
+							if let tokenizer::Token::Digit(start) = stok {
+								if let tokenizer::Token::Digit(end) = etok {
+									// string has no more chars than bytes //;
+									let end: usize = end.min(s.len() as u128) as usize;
+									let start: usize = start.min(end as u128) as usize;
+									return vec![tokenizer::Token::Str(
+										s.chars().skip(start).take(end-start).collect()
+									)];
+								}
+
+								make_err!(argt, i, etok, 2);
 							}
 
-							if vl > 2 {
-								let tok: tokenizer::Token =
-										catch_vec.pop().unwrap().remove(0);
+							make_err!(argt, i, stok, 1);
+						}
+
+						make_err!(argt, i, tok, 3);
+					}
+
+					"str-index-of" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let a: Vec<String> = str_args(&i, ret, 1);
+						if let Some(b) = a[1].find(a[0].as_str()) {
+							return vec![tokenizer::Token::Digit(a[1][..b].chars().count() as u128)];
+						}
+
+						return vec![tokenizer::Token::Nil];
+					}
+
+					"str-contains"|"str-starts-with"|"str-ends-with" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let a: Vec<String> = str_args(&i, ret, 1);
+						return vec![tokenizer::Token::Bool(match i.as_str() {
+							"str-contains" => a[1].contains(a[0].as_str()),
+							"str-starts-with" => a[1].starts_with(a[0].as_str()),
+							_ => a[1].ends_with(a[0].as_str()),
+						})];
+					}
+
+					"str-replace"|"str-replace-all" => {
+						let vl: usize = ret.len();
+						if vl != 3 {
+							make_err!(argcn, i, vl, 3);
+						}
+
+						let a: Vec<String> = str_args(&i, ret, 1);
+						if a[0].is_empty() {
+							return vec![tokenizer::Token::Str(a[2].clone())];
+						}
+
+						return vec![tokenizer::Token::Str(if i == "str-replace" {
+							a[2].replacen(a[0].as_str(), a[1].as_str(), 1)
+						} else {
+							a[2].replace(a[0].as_str(), a[1].as_str())
+						})];
+					}
+
+					"str-upper"|"str-lower"|"str-trim"|"str-trim-left"|"str-trim-right" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let a: Vec<String> = str_args(&i, ret, 1);
+						return vec![tokenizer::Token::Str(match i.as_str() {
+							"str-upper" => a[0].to_uppercase(),
+							"str-lower" => a[0].to_lowercase(),
+							"str-trim" => String::from(a[0].trim()),
+							"str-trim-left" => String::from(a[0].trim_start()),
+							_ => String::from(a[0].trim_end()),
+						})];
+					}
+
+					"str-pad-left"|"str-pad-right" => {
+						let vl: usize = ret.len();
+						if !(2..=3).contains(&vl) {
+							make_err!(argc, i, vl, 2, 3);
+						}
+
+						let wtok: tokenizer::Token = ret.remove(0);
+						let width: u128;
+						if let tokenizer::Token::Digit(d) = wtok {
+							width = d;
+						} else {
+							make_err!(argt, i, wtok, 1);
+						}
 
-								if let tokenizer::Token::Ident(_) = tok {
-									let argc: usize = catch_vec.len();
-									let mut rest_toks: Vec<tokenizer::Token> = Vec::new();
+						let a: Vec<String> = str_args(&i, ret, 2);
+						let (fill, s) = if vl == 3 {
+							(a[0].clone(), a[1].clone())
+						} else {
+							(String::from(" "), a[0].clone())
+						};
 
-									for _ in 0..argc {
-										rest_toks.push(tokenizer::Token::OPair);
-										rest_toks
-											.push(tokenizer::Token::Ident(String::from("rm")));
-										rest_toks.push(tokenizer::Token::Digit(0));
-									}
+						let n: usize = s.chars().count();
+						if n as u128 >= width || fill.is_empty() {
+							return vec![tokenizer::Token::Str(s)];
+						}
 
-									rest_toks.push(tokenizer::Token::OPair);
-									rest_toks.push(tokenizer::Token::Ident(String::from("%%")));
-									rest_toks.push(tokenizer::Token::CPair);
+						let widest: usize = fill.chars().map(char::len_utf8).max().unwrap();
+						match (width - n as u128).checked_mul(widest as u128) {
+							Some(len) if len + s.len() as u128 <= STR_MAX_LEN as u128 => {}
+							_ => {
+								raise!(flow, "`{}`: width {}: string is too long, limit is {} bytes", i, width, STR_MAX_LEN);
+							}
+						}
 
-									for _ in 0..argc {
-										rest_toks.push(tokenizer::Token::CPair);
-									}
+						let pad: String = fill.chars().cycle().take(width as usize - n).collect();
+						return vec![tokenizer::Token::Str(if i == "str-pad-left" {
+							pad + &s
+						} else {
+							s + &pad
+						})];
+					}
 
-									let mut j: usize = 0;
-									while j < code.len() {
-										if code[j] == tok.clone() {
-											code.remove(j);
-											for (o, item) in rest_toks.clone().into_iter()
-												.enumerate() {
-												code.insert(j+o, item);
-											}
+					"str-repeat" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
 
-											j += rest_toks.len();
-										}
-										j += 1;
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						let ntok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Digit(n) = ntok {
+							if let tokenizer::Token::Str(s) = tok {
+								match (s.len() as u128).checked_mul(n) {
+									Some(len) if len <= STR_MAX_LEN as u128 => {
+										return vec![tokenizer::Token::Str(s.repeat(n as usize))];
 									}
 
-									for _ in 0..argc {
-										let arg_i: usize = catch_vec.len()-1;
-										let tok_arg: tokenizer::Token =
-												catch_vec.pop().unwrap().remove(0);
-										
-										let arg_vec: Vec<tokenizer::Token> = vec![
-											tokenizer::Token::OPair,
-											tokenizer::Token::Ident(String::from("%")),
-											tokenizer::Token::Digit(arg_i as u128),
-											tokenizer::Token::CPair,
-										];
-
-										if let tokenizer::Token::Ident(_) = tok_arg {
-											let mut k: usize = 0;
-											while k < code.len() {
-												if code[k] == tok_arg.clone() {
-													code.remove(k);
-													for (o, item) in arg_vec.clone().into_iter()
-														.enumerate() {
-														code.insert(k+o, item);
-													}
-
-													k += 4;
-												}
-												k += 1;
-											}
-										} else {
-											make_err!(argt, i, tok_arg, arg_i+1);
-										}
+									_ => {
+										raise!(flow, "`{}`: {} bytes repeated {} times: string is too long, limit is {} bytes",
+													 i, s.len(), n, STR_MAX_LEN);
 									}
-								} else {
-									make_err!(argt, i, tok, "rest");
 								}
 							}
-								
-							funcs.insert(fi.clone(), code);
-							return vec![];
-						}
 
-						make_err!(argt, i, funn, 1);
-					}
+							make_err!(argt, i, tok, 2);
+						}
 
-					_ => {
-						unreachable!("what 2");
+						make_err!(argt, i, ntok, 1);
 					}
-				}
-			}
-
-			_ => {
-				unreachable!("what 1");
-			}
-		}
-	}
 
-	if depth > 0 && ret.len() > 0 {
-		let fun: tokenizer::Token = ret.remove(0);
-		match fun {
-			tokenizer::Token::Ident(i) => {
-				match i.as_str() {
-					"as-int" => {
+					"mut?" => {
 						let vl: usize = ret.len();
 						if vl != 1 {
 							make_err!(argcn, i, vl, 1);
 						}
 
-						let t = ret.pop().unwrap();
-						if let tokenizer::Token::Str(ref s) = t {
-							if s.len() == 1 {
-								return vec![
-									tokenizer::Token::Digit(s.chars().nth(0).unwrap() as u128)
-								];
-							} else {
-								make_err!(value, i, t, "single char");
-							}
-						} else {
-							make_err!(argt, i, t, 1);
-						}
-					}
-
-					"as-str" => {
-						let vl: usize = ret.len();
-						if vl == 0 {
-							make_err!(argcf, i, 0, 1);
-						}
+						let tok: tokenizer::Token = ret.pop().unwrap();
 
-						let mut tmp: Vec<u8> = Vec::new();
-						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(b) = t {
-								tmp.push(b as u8);
-								continue
+						if let tokenizer::Token::Ident(i) = tok {
+							if vars.contains(&i) {
+								return vec![tokenizer::Token::Bool(true)];
+							} else {
+								return vec![tokenizer::Token::Bool(false)];
 							}
-
-							make_err!(argt, i, t, index+1);
 						}
 
-						return vec![
-							tokenizer::Token::Str(String::from_utf8_lossy(tmp.as_slice())
-								.to_string())
-						];
+						make_err!(argt, i, tok, 1);
 					}
 
-					"as-char" => {
+					"def?" => {
 						let vl: usize = ret.len();
 						if vl != 1 {
 							make_err!(argcn, i, vl, 1);
 						}
 
-						let t = ret.pop().unwrap();
-						if let tokenizer::Token::Digit(d) = t{
-							return vec![
-								tokenizer::Token::Str(String::from_utf8_lossy(&[d as u8])
-									.to_string())
-							];
-						} else {
-							make_err!(argt, i, t, 1);
+						let tok = ret.pop().unwrap();
+						if let tokenizer::Token::Ident(i) = tok {
+							return
+								vec![tokenizer::Token::Bool(funcs.contains_key(&i))];
 						}
+						
+						make_err!(argt, i, tok, 1);						
 					}
 
-					"get-env" => {
+					"undef?" => {
 						let vl: usize = ret.len();
 						if vl != 1 {
 							make_err!(argcn, i, vl, 1);
 						}
 
-						let tok: tokenizer::Token = ret.pop().unwrap();
-						if let tokenizer::Token::Str(s) = tok {
-							return vec![tokenizer::Token::Str(
-								if let Ok(r) = env::var(s) {
-									r
-								} else {
-									String::new()
-								}
-							)];
-						} else {
-							make_err!(argt, i, tok, 1);
+						let tok = ret.pop().unwrap();
+						if let tokenizer::Token::Ident(i) = tok {
+							return
+								vec![tokenizer::Token::Bool(!funcs.contains_key(&i))];
 						}
+						
+						make_err!(argt, i, tok, 1);						
 					}
 
-					"input" => {
-						let vl: usize = ret.len();
-						if vl == 0 {
+					
+					// (int values... :radix r) parses strings in radix r //;
+					"int" => {
+						let radix: u32 = match keyword_args(&mut ret, &["radix"]) {
+							Ok(kws) => match kws[0] {
+								None => 10,
+								Some(tokenizer::Token::Digit(d)) if (2..=36).contains(&d) => d as u32,
+								Some(ref t) => {
+									make_err!(value, i, t, "radix in 2..=36");
+								}
+							},
+
+							Err(e) => {
+								raise!(flow, "`{}`: {}", i, e);
+							}
+						};
+
+						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 
 						let mut tmp: Vec<tokenizer::Token> = Vec::new();
-						mem::swap(&mut ret, &mut tmp);
+						mem::swap(&mut tmp, &mut ret);
 
-						let stdin = io::stdin();
-						let mut stdout = io::stdout();
 						for (index, t) in tmp.into_iter().enumerate() {
-							if let tokenizer::Token::Str(s) = t {
-								print!("{}", s);
-								stdout.flush().expect("stdout flush failed");
-								let mut rs: String = String::new();
-								stdin.read_line(&mut rs).expect("stdin read_line failed");
-								ret.push(tokenizer::Token::Str(rs.trim().to_string()));
-								continue
-							}
+							match t {
+								tokenizer::Token::Digit(_)|tokenizer::Token::Big(_) => {
+									ret.push(t);
+								}
 
-							make_err!(argt, i, t, index+1);
+								tokenizer::Token::Bool(b) => {
+									ret.push(tokenizer::Token::Digit(b as u128));
+								}
+
+								tokenizer::Token::Str(ref s) => {
+									if let Some(b) = bigint::BigInt::parse(s, radix) {
+										ret.push(big_token(b));
+									} else {
+										make_err!(value, i, t, "integer");
+									}
+								}
+
+								_ => {
+									make_err!(argt, i, t, index+1);
+								}
+							}
 						}
 
 						return ret;
 					}
 
-					"file-read" => {
-						let vl: usize = ret.len();
-						if vl == 0 {
+					// (str values... :radix r :pad width) writes numbers in radix r,
+					//   padded with zeros to width //;
+					"str" => {
+						let (radix, width): (u32, usize) = match keyword_args(&mut ret, &["radix", "pad"]) {
+							Ok(kws) => {
+								let radix: u32 = match kws[0] {
+									None => 10,
+									Some(tokenizer::Token::Digit(d)) if (2..=36).contains(&d) => d as u32,
+									Some(ref t) => {
+										make_err!(value, i, t, "radix in 2..=36");
+									}
+								};
+
+								let width: usize = match kws[1] {
+									None => 0,
+									Some(tokenizer::Token::Digit(d)) if d <= STR_MAX_LEN as u128 => d as usize,
+									Some(tokenizer::Token::Digit(d)) => {
+										raise!(flow, "`{}`: width {}: string is too long, limit is {} bytes", i, d, STR_MAX_LEN);
+									}
+
+									Some(ref t) => {
+										make_err!(value, i, t, "width of number");
+									}
+								};
+
+								(radix, width)
+							}
+
+							Err(e) => {
+								raise!(flow, "`{}`: {}", i, e);
+							}
+						};
+
+						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
-						
+
 						let mut tmp: Vec<tokenizer::Token> = Vec::new();
-						mem::swap(&mut ret, &mut tmp);
+						mem::swap(&mut tmp, &mut ret);
 
 						for (index, t) in tmp.into_iter().enumerate() {
-							if let tokenizer::Token::Str(s) = t {
-								ret.push(
-									tokenizer::Token::Str(
-										fs::read_to_string(s.as_str())
-											.expect("failed to read file")));
+							if radix != 10 || width > 0 {
+								if let Some(b) = big_of(&t) {
+									let digits: String = b.abs().to_string_radix(radix);
+									let mut st: String = String::new();
+									if b.is_neg() {
+										st.push('-');
+									}
 
-								continue
+									for _ in digits.len()..width {
+										st.push('0');
+									}
+
+									st += &digits;
+									ret.push(tokenizer::Token::Str(st));
+									continue
+								}
 							}
 
-							make_err!(argt, i, t, index+1);
+							match t {
+								tokenizer::Token::Digit(d) => {
+									ret.push(tokenizer::Token::Str(format!("{}", d)));
+								}
+
+								tokenizer::Token::Nil => {
+									ret.push(tokenizer::Token::Str(String::from("nil")));
+								}
+
+								tokenizer::Token::Bool(b) => {
+									ret.push(tokenizer::Token::Str(format!("{}", b)));
+								}
+
+								tokenizer::Token::Big(b) => {
+									ret.push(tokenizer::Token::Str(format!("{}", b)));
+								}
+
+								tokenizer::Token::Record(r) => {
+									ret.push(tokenizer::Token::Str(r.show()));
+								}
+
+								tokenizer::Token::Vector(v) => {
+									ret.push(tokenizer::Token::Str(v.show()));
+								}
+
+								tokenizer::Token::Set(s) => {
+									ret.push(tokenizer::Token::Str(s.show()));
+								}
+
+								tokenizer::Token::Str(_) => {
+									ret.push(t);
+								}
+
+								_ => {
+									make_err!(argt, i, t, index+1);
+								}
+							}
 						}
 
 						return ret;
 					}
-
-					"file-write" => {
+					
+					"map"|"flat-map"|"filter"|"any"|"all"|"count"|"take-while"|"drop-while"|"partition" => {
 						let vl: usize = ret.len();
-						if vl != 2 {
-							make_err!(argcn, i, vl, 2);
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
 						}
 
-						let fc: tokenizer::Token = ret.pop().unwrap();
-						let fl: tokenizer::Token = ret.pop().unwrap();
+						let f: tokenizer::Token = ret.remove(0);
+						unless!(let tokenizer::Token::Ident(_) = f, {
+							make_err!(argt, i, f, 1);
+						});
 
-						if let tokenizer::Token::Str(fls) = fl {
-							if let tokenizer::Token::Str(fcs) = fc {
-								fs::write(fls.as_str(), fcs.as_str())
-									.expect("failed to write file");
+						if let [tokenizer::Token::Iter(it)] = ret.as_slice() {
+							let adapter: Option<iter::Adapter> = match i.as_str() {
+								"map"|"flat-map" => Some(iter::Adapter::Map(f.clone())),
+								"filter" => Some(iter::Adapter::Filter(f.clone())),
+								"take-while" => Some(iter::Adapter::TakeWhile(f.clone())),
+								"drop-while" => Some(iter::Adapter::DropWhile(f.clone())),
+								_ => None,
+							};
+
+							if let Some(a) = adapter {
+								return vec![tokenizer::Token::Iter(iter::Lazy::adapt(a, it.clone()))];
+							}
+						}
 
-								return vec![tokenizer::Token::Digit(1)];
+						ret = force(ret, depth, funcs, lambdas, vars, flow);
+						let mut res: Vec<tokenizer::Token> = Vec::new();
+						let mut rest: Vec<tokenizer::Token> = Vec::new();
+						let mut taking: bool = true;
+						for t in ret.into_iter() {
+							if !taking {
+								res.push(t);
+								continue
+							}
+
+							let r: Vec<tokenizer::Token> =
+									call(&f, vec![t.clone()], depth, funcs, lambdas, vars, flow);
+
+							if flow.interrupted() {
+								return vec![];
+							}
+
+							match i.as_str() {
+								"map"|"flat-map" => {
+									res.extend(r);
+								}
+
+								"take-while" if !truthy(&r) => {
+									break
+								}
+
+								"drop-while" if !truthy(&r) => {
+									taking = false;
+									res.push(t);
+								}
+
+								"drop-while" => {
+									// VOID //;
+								}
+
+								"any" if truthy(&r) => {
+									return vec![tokenizer::Token::Bool(true)];
+								}
+
+								"all" if !truthy(&r) => {
+									return vec![tokenizer::Token::Bool(false)];
+								}
+
+								"any"|"all" => {
+									// VOID //;
+								}
+
+								"partition" if !truthy(&r) => {
+									rest.push(t);
+								}
+
+								_ if truthy(&r) => {
+									res.push(t);
+								}
+
+								_ => {
+									// VOID //;
+								}
 							}
-							
-							make_err!(argt, i, fc, 2);	
 						}
-					
-						make_err!(argt, i, fl, 1);	
+
+						return match i.as_str() {
+							"any" => vec![tokenizer::Token::Bool(false)],
+							"all" => vec![tokenizer::Token::Bool(true)],
+							"count" => vec![tokenizer::Token::Digit(res.len() as u128)],
+							"partition" => vec![
+								tokenizer::Token::Vector(vector::Vector::new(&res)),
+								tokenizer::Token::Vector(vector::Vector::new(&rest)),
+							],
+							_ => res,
+						};
 					}
-					
-					"int?" => {
+
+					"reduce"|"fold" => {
 						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+						let min: usize = if i == "fold" { 2 } else { 1 };
+						if vl < min {
+							make_err!(argcf, i, vl, min);
 						}
 
-						if let tokenizer::Token::Digit(_) = ret.pop().unwrap() {
-							return vec![tokenizer::Token::Digit(1)];
+						let f: tokenizer::Token = ret.remove(0);
+						unless!(let tokenizer::Token::Ident(_) = f, {
+							make_err!(argt, i, f, 1);
+						});
+
+						if ret.is_empty() {
+							return vec![tokenizer::Token::Nil];
 						}
-						
-						return vec![tokenizer::Token::Digit(0)];
-					}
 
-					"str?" => {
-						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+						// reduce goes from the right like it did in std, fold from the left //;
+						if i == "reduce" {
+							let mut acc: Vec<tokenizer::Token> = vec![ret.pop().unwrap()];
+							for t in ret.into_iter().rev() {
+								acc.insert(0, t);
+								acc = call(&f, acc, depth, funcs, lambdas, vars, flow);
+								if flow.interrupted() {
+									return vec![];
+								}
+							}
+
+							return acc;
 						}
 
-						if let tokenizer::Token::Str(_) = ret.pop().unwrap() {
-							return vec![tokenizer::Token::Digit(1)];
+						let mut acc: Vec<tokenizer::Token> = vec![ret.remove(0)];
+						for t in ret.into_iter() {
+							acc.push(t);
+							acc = call(&f, acc, depth, funcs, lambdas, vars, flow);
+							if flow.interrupted() {
+								return vec![];
+							}
 						}
-						
-						return vec![tokenizer::Token::Digit(0)];
+
+						return acc;
 					}
 
-					"ident?" => {
-						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+					"enumerate" => {
+						if let [tokenizer::Token::Iter(it)] = ret.as_slice() {
+							return vec![tokenizer::Token::Iter(
+								iter::Lazy::adapt(iter::Adapter::Enumerate(0), it.clone())
+							)];
 						}
 
-						if let tokenizer::Token::Ident(_) = ret.pop().unwrap() {
-							return vec![tokenizer::Token::Digit(1)];
+						ret = force(ret, depth, funcs, lambdas, vars, flow);
+						let mut res: Vec<tokenizer::Token> = Vec::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							res.push(tokenizer::Token::Digit(index as u128));
+							res.push(t);
 						}
-						
-						return vec![tokenizer::Token::Digit(0)];
+
+						return res;
 					}
 
-					"ident-name" => {
+					"take"|"skip" => {
 						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
 						}
 
-						let tok: tokenizer::Token = ret.pop().unwrap();
-
-						if let tokenizer::Token::Ident(s) = tok {
-							return vec![tokenizer::Token::Str(s)];
+						let ntok: tokenizer::Token = ret.remove(0);
+						let n: u128;
+						if let tokenizer::Token::Digit(d) = ntok {
+							n = d;
 						} else {
-							make_err!(argt, i, tok, 1);
+							make_err!(argt, i, ntok, 1);
 						}
-					}
 
-					"ident-addr" => {
-						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+						if let [tokenizer::Token::Iter(it)] = ret.as_slice() {
+							let a: iter::Adapter = if i == "take" { iter::Adapter::Take(n) } else { iter::Adapter::Skip(n) };
+							return vec![tokenizer::Token::Iter(iter::Lazy::adapt(a, it.clone()))];
 						}
 
-						let tok: tokenizer::Token = ret.pop().unwrap();
-						
-						if let tokenizer::Token::Ident(s) = tok {
-							return vec![tokenizer::Token::Str(
-								if let Some(val) = funcs.get(&s) {
-									format!("{:p}", val.as_ptr())
-								} else if let Some(val) = vars.get(&s) {
-									format!("{:p}", val.as_ptr())
-								} else {
-									String::from("0x0")
-								}
-							)];
+						ret = force(ret, depth, funcs, lambdas, vars, flow);
+						let n: usize = n.min(ret.len() as u128) as usize;
+						if i == "take" {
+							ret.truncate(n);
 						} else {
-							make_err!(argt, i, tok, 1);
+							ret.drain(..n);
 						}
+
+						return ret;
 					}
 
-					"bytes" => {
+					"next"|"resume"|"send" => {
 						let vl: usize = ret.len();
-						if vl != 1 {
+						if i == "send" && vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						} else if i != "send" && vl != 1 {
 							make_err!(argcn, i, vl, 1);
 						}
 
-						let tok: tokenizer::Token = ret.pop().unwrap();
-						if let tokenizer::Token::Str(s) = tok {
-							for i in s.bytes() {
-								ret.push(tokenizer::Token::Digit(i as u128));
+						let tok: tokenizer::Token = ret.remove(0);
+						if let tokenizer::Token::Iter(it) = tok {
+							if i == "send" {
+								let v: Vec<tokenizer::Token> = force(ret, depth, funcs, lambdas, vars, flow);
+								it.0.lock().unwrap().send_val = Some(v);
 							}
-						} else {
-							make_err!(argt, i, tok, 1);
+
+							return vec![iter_next(&it, depth, funcs, lambdas, vars, flow)
+								.unwrap_or(tokenizer::Token::Nil)];
 						}
 
+						make_err!(argt, i, tok, 1);
+					}
+
+					"iter?" => {
+						return vec![tokenizer::Token::Bool(
+							!ret.is_empty() && ret.iter().all(|t| matches!(t, tokenizer::Token::Iter(_)))
+						)];
+					}
+
+					"collect" => {
 						return ret;
 					}
 
-					"chars" => {
+					"generator" => {
 						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
 						}
 
-						let tok: tokenizer::Token = ret.pop().unwrap();
-						if let tokenizer::Token::Str(s) = tok {
-							for i in s.chars() {
-								ret.push(tokenizer::Token::Str(String::from(i)));
+						let f: tokenizer::Token = ret.remove(0);
+						unless!(let tokenizer::Token::Ident(_) = f, {
+							make_err!(argt, i, f, 1);
+						});
+
+						let (resume_tx, resume_rx) = mpsc::channel::<(Vec<tokenizer::Token>, iter::Shared)>();
+						let (values_tx, values_rx) = mpsc::channel::<iter::GenMsg>();
+
+						// functions, lambdas and globals come with every resume, so generator
+						//   doesn't copy them, and sees changes made by consumer
+						// stack is only reserved, memory is taken when it's used //;
+						thread::Builder::new()
+							.stack_size(64 << 20)
+							.spawn(move || {
+								// waits for the first element to be asked //;
+								let shared: iter::Shared = match resume_rx.recv() {
+									Ok((_, shared)) => shared,
+									Err(_) => return,
+								};
+
+								let mut gen_funcs: HashMap<String, func::Func> = HashMap::new();
+								let mut gen_lambdas: usize = 0;
+								let mut gen_vars: vars::Vars = vars::Vars::new();
+								take_back(shared, &mut gen_funcs, &mut gen_lambdas, &mut gen_vars);
+
+								let values: mpsc::Sender<iter::GenMsg> = values_tx.clone();
+								let mut gen_flow: Flow = Flow {
+									signal: Signal::Normal,
+									loops: 0,
+									funcs: 1,
+									gen: Some(iter::GenLink {
+										values: values_tx,
+										resume: resume_rx,
+									}),
+								};
+
+								call(&f, ret, 0, &mut gen_funcs, &mut gen_lambdas,
+										 &mut gen_vars, &mut gen_flow);
+
+								let shared: iter::Shared = lend(&mut gen_funcs, &mut gen_lambdas, &mut gen_vars);
+								let _ = values.send(match gen_flow.signal {
+									Signal::Error(e) => iter::GenMsg::Error(e, shared),
+									_ => iter::GenMsg::Done(shared),
+								});
+							})
+							.expect("failed to spawn generator thread");
+
+						return vec![tokenizer::Token::Iter(iter::Lazy::new(iter::Source::Gen(iter::Gen {
+							resume: resume_tx,
+							values: values_rx,
+						})))];
+					}
+
+					"yield" => {
+						let link: &iter::GenLink = match flow.gen {
+							Some(ref link) => link,
+							None => {
+								make_err!(outside, i, "generator");
 							}
+						};
 
-							return ret;
+						let shared: iter::Shared = lend(funcs, lambdas, vars);
+						if link.values.send(iter::GenMsg::Yield(ret, shared)).is_ok() {
+							if let Ok((v, shared)) = link.resume.recv() {
+								take_back(shared, funcs, lambdas, vars);
+								return v;
+							}
 						}
-						
-						make_err!(argt, i, tok, 1);
+
+						// nobody can resume generator anymore, its thread just stops //;
+						panic::resume_unwind(Box::new(()));
 					}
 
-					"str-collect" => {
+					"input-lines" => {
 						let vl: usize = ret.len();
-						if vl == 0 {
-							make_err!(argcf, i, vl, 1);
+						if vl != 0 {
+							make_err!(argcn, i, vl, 0);
 						}
 
-						let mut s: String = String::new();
+						return vec![tokenizer::Token::Iter(iter::Lazy::new(iter::Source::Stdin))];
+					}
+
+					// iterator over given elements, it stays iterator when stored //;
+					"iter" => {
+						let it: iter::Lazy = iter::Lazy::new(iter::Source::Items);
+						it.0.lock().unwrap().buf.extend(ret);
+						return vec![tokenizer::Token::Iter(it)];
+					}
+
+					"range-from" => {
+						let vl: usize = ret.len();
+						if vl == 0 || vl > 2 {
+							make_err!(argc, i, vl, 1, 2);
+						}
+
+						let mut d: Vec<u128> = Vec::new();
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Str(ref ts) = t {
-								s += ts;
+							if let tokenizer::Token::Digit(n) = t {
+								d.push(n);
 								continue
 							}
 
 							make_err!(argt, i, t, index+1);
 						}
 
-						return vec![tokenizer::Token::Str(s)];
+						return vec![tokenizer::Token::Iter(iter::Lazy::new(iter::Source::Range {
+							next: d[0],
+							end: None,
+							step: *d.get(1).unwrap_or(&1),
+						}))];
 					}
-					
-					"mut?" => {
+
+					"file-lines" => {
 						let vl: usize = ret.len();
 						if vl != 1 {
 							make_err!(argcn, i, vl, 1);
 						}
 
 						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let tokenizer::Token::Str(path) = tok {
+							match fs::File::open(&path) {
+								Ok(f) => {
+									return vec![tokenizer::Token::Iter(iter::Lazy::new(
+										iter::Source::Lines(io::BufRead::lines(io::BufReader::new(f)))
+									))];
+								}
 
-						if let tokenizer::Token::Ident(i) = tok {
-							if let Some(_) = vars.get(&i) {
-								return vec![tokenizer::Token::Digit(1)];
-							} else {
-								return vec![tokenizer::Token::Digit(0)];
+								Err(e) => {
+									raise!(flow, "`{}`: {}: {}", i, path, e);
+								}
 							}
 						}
 
 						make_err!(argt, i, tok, 1);
 					}
 
-					"def?" => {
-						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+					"sort"|"sort-desc" => {
+						// stable both ways, equal elements keep their order //;
+						if i == "sort-desc" {
+							ret.sort_by(|a, b| cmp_tokens(b, a));
+						} else {
+							ret.sort_by(cmp_tokens);
 						}
 
-						let tok = ret.pop().unwrap();
-						if let tokenizer::Token::Ident(i) = tok {
-							return
-								vec![tokenizer::Token::Digit(funcs.contains_key(&i) as u128)];
-						}
-						
-						make_err!(argt, i, tok, 1);						
+						return ret;
 					}
 
-					"undef?" => {
+					"sort-by"|"min-by"|"max-by" => {
 						let vl: usize = ret.len();
-						if vl != 1 {
-							make_err!(argcn, i, vl, 1);
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
 						}
 
-						let tok = ret.pop().unwrap();
-						if let tokenizer::Token::Ident(i) = tok {
-							return
-								vec![tokenizer::Token::Digit(!funcs.contains_key(&i) as u128)];
-						}
-						
-						make_err!(argt, i, tok, 1);						
-					}
+						let f: tokenizer::Token = ret.remove(0);
+						unless!(let tokenizer::Token::Ident(_) = f, {
+							make_err!(argt, i, f, 1);
+						});
 
-					
-					"int" => {
-						if ret.len() == 0 {
-							make_err!(argcf, i, 0, 1);
-						}
+						let mut keyed: Vec<(Vec<tokenizer::Token>, tokenizer::Token)> = Vec::new();
+						for t in ret.into_iter() {
+							let key: Vec<tokenizer::Token> =
+									call(&f, vec![t.clone()], depth, funcs, lambdas, vars, flow);
 
-						let mut tmp: Vec<tokenizer::Token> = Vec::new();
-						mem::swap(&mut tmp, &mut ret);
+							if flow.interrupted() {
+								return vec![];
+							}
 
-						for (index, t) in tmp.into_iter().enumerate() {
-							match t {
-								tokenizer::Token::Digit(_) => {
-									ret.push(t);
-								}
+							keyed.push((key, t));
+						}
 
-								tokenizer::Token::Str(s) => {
-									ret.push(
-										tokenizer::Token::Digit(
-											s.parse::<u128>()
-												.expect(
-													format!("ERR: {}: not valid integer for `int`", s)
-														.as_str())));
-								}
+						match i.as_str() {
+							"sort-by" => {
+								keyed.sort_by(|a, b| cmp_seqs(&a.0, &b.0));
+								return keyed.into_iter().map(|(_, t)| t).collect();
+							}
 
-								_ => {
-									make_err!(argt, i, t, index+1);
+							_ => {
+								let want: Ordering = if i == "min-by" { Ordering::Less } else { Ordering::Greater };
+								let mut best: Option<(Vec<tokenizer::Token>, tokenizer::Token)> = None;
+								for (key, t) in keyed.into_iter() {
+									let better: bool = match best {
+										Some((ref bkey, _)) => cmp_seqs(&key, bkey) == want,
+										None => true,
+									};
+
+									if better {
+										best = Some((key, t));
+									}
 								}
+
+								return vec![best.map(|(_, t)| t).unwrap_or(tokenizer::Token::Nil)];
 							}
 						}
-
-						return ret;
 					}
 
-					"str" => {
-						if ret.len() == 0 {
-							make_err!(argcf, i, 0, 1);
+					"sort-with" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
 						}
 
-						let mut tmp: Vec<tokenizer::Token> = Vec::new();
-						mem::swap(&mut tmp, &mut ret);
+						let f: tokenizer::Token = ret.remove(0);
+						unless!(let tokenizer::Token::Ident(_) = f, {
+							make_err!(argt, i, f, 1);
+						});
 
-						for (index, t) in tmp.into_iter().enumerate() {
-							match t {
-								tokenizer::Token::Digit(d) => {
-									ret.push(tokenizer::Token::Str(format!("{}", d)));
-								}
+						// comparator returns number like `compare`: negative, 0 or positive //;
+						ret.sort_by(|a, b| {
+							if flow.interrupted() {
+								return Ordering::Equal;
+							}
 
-								tokenizer::Token::Str(_) => {
-									ret.push(t);
-								}
+							let r: Vec<tokenizer::Token> =
+									call(&f, vec![a.clone(), b.clone()], depth, funcs, lambdas, vars, flow);
 
+							match r.as_slice() {
+								[tokenizer::Token::Digit(0)] => Ordering::Equal,
+								[tokenizer::Token::Digit(_)] => Ordering::Greater,
+								[tokenizer::Token::Big(b)] if b.is_neg() => Ordering::Less,
+								[tokenizer::Token::Big(_)] => Ordering::Greater,
+								_ if flow.interrupted() => Ordering::Equal,
 								_ => {
-									make_err!(argt, i, t, index+1);
+									let t: tokenizer::Token = r.into_iter().next().unwrap_or(tokenizer::Token::Nil);
+									make_err!(value, i, t, "number returned by comparator");
 								}
 							}
+						});
+
+						if flow.interrupted() {
+							return vec![];
 						}
 
 						return ret;
 					}
-					
+
+					// set keeps the first of equal values, in order of insertion //;
+					"uniq" => {
+						return set::Set::new(ret).items();
+					}
+
+					"dedup" => {
+						ret.dedup();
+						return ret;
+					}
+
+					"reverse" => {
+						ret.reverse();
+						return ret;
+					}
+
+					"bsearch" => {
+						let vl: usize = ret.len();
+						if vl == 0 {
+							make_err!(argcf, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.remove(0);
+						return vec![match ret.binary_search_by(|t| cmp_tokens(t, &tok)) {
+							Ok(index) => tokenizer::Token::Digit(index as u128),
+							Err(_) => tokenizer::Token::Nil,
+						}];
+					}
+
 					"len" => {
 						return vec![tokenizer::Token::Digit(ret.len() as u128)];
 					}
 					
 					"nth" => {
 						let vl: usize = ret.len();
-						if vl < 2 {
-							make_err!(argcf, i, vl ,2);
+						if vl < 1 {
+							make_err!(argcf, i, vl, 1);
 						}
 
 						let tok: tokenizer::Token = ret.remove(0);
 
 						if let tokenizer::Token::Digit(d) = tok {
-							let i: usize = d as usize;
-							if i >= vl-1 {
-								eprintln!("ERR: `{} {}`: index out of bounds, max {}",
-													i, d, vl-1);
-
-								return vec![];
+							if d >= (vl-1) as u128 {
+								return vec![tokenizer::Token::Nil];
 							}
 
-							return vec![ret[i].clone()];
+							return vec![ret[d as usize].clone()];
 						}
 
 						make_err!(argt, i, tok, 1);
@@ -992,7 +3767,11 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 						
 						let tok: tokenizer::Token = ret.remove(0);
 						match tok {
-							tokenizer::Token::Str(_)|tokenizer::Token::Digit(_) => {
+							tokenizer::Token::Str(_)|tokenizer::Token::Digit(_)|
+							tokenizer::Token::Nil|tokenizer::Token::Bool(_)|
+							tokenizer::Token::Big(_)|tokenizer::Token::Ident(_)|
+							tokenizer::Token::Record(_)|tokenizer::Token::Vector(_)|
+							tokenizer::Token::Set(_) => {
 								// VOID //;
 							}
 
@@ -1003,21 +3782,25 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 						
 						for (index, t) in ret.into_iter().enumerate() {
 							match t {
-								tokenizer::Token::Str(_)|tokenizer::Token::Digit(_) => {
+								tokenizer::Token::Str(_)|tokenizer::Token::Digit(_)|
+								tokenizer::Token::Nil|tokenizer::Token::Bool(_)|
+								tokenizer::Token::Big(_)|tokenizer::Token::Ident(_)|
+								tokenizer::Token::Record(_)|tokenizer::Token::Vector(_)|
+								tokenizer::Token::Set(_) => {
 									// VOID //;
 								}
 
 								_ => {
-									make_err!(argt, i, t, index+1);
+									make_err!(argt, i, t, index+2);
 								}
 							}
 							
 							if t != tok {
-								return vec![tokenizer::Token::Digit(0)];
+								return vec![tokenizer::Token::Bool(false)];
 							}
 						}
 
-						return vec![tokenizer::Token::Digit(1)];
+						return vec![tokenizer::Token::Bool(true)];
 					}
 
 					"pr" => {
@@ -1038,6 +3821,30 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 									st += &format!("{d}");
 								}
 
+								tokenizer::Token::Nil => {
+									st += "nil";
+								}
+
+								tokenizer::Token::Bool(b) => {
+									st += &format!("{b}");
+								}
+
+								tokenizer::Token::Big(b) => {
+									st += &format!("{b}");
+								}
+
+								tokenizer::Token::Record(r) => {
+									st += &r.show();
+								}
+
+								tokenizer::Token::Vector(v) => {
+									st += &v.show();
+								}
+
+								tokenizer::Token::Set(s) => {
+									st += &s.show();
+								}
+
 								_ => {
 									make_err!(argt, i, t, index+1);
 								}
@@ -1048,153 +3855,337 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 						return vec![];
 					}
 
-					">" => {
-						let vl: usize = ret.len();
-						
-						if vl != 2 {
-							make_err!(argcn, i, vl, 2);
+					"+" => {
+						if ret.len() == 0 {
+							make_err!(argcf, i, 0, 1);
 						}
 						
-						let tok2: tokenizer::Token = ret.pop().unwrap();
-						let tok: tokenizer::Token = ret.pop().unwrap();
-						if let tokenizer::Token::Digit(d) = tok {
-							if let tokenizer::Token::Digit(d2) = tok2 {
-								return vec![tokenizer::Token::Digit((d > d2) as u128)];
-							} else {
-								make_err!(argt, i, tok2, 2);
-							}
+						let tok: tokenizer::Token = ret.remove(0);
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
+
+						for (index, t) in ret.into_iter().enumerate() {
+							if let Some(d) = big_of(&t) {
+								r = r.add(&d);
+								continue
+							}
+							
+							make_err!(argt, i, t, index+2);
+						}
+
+						return vec![big_token(r)];
 					}
-	
-					"<" => {
-						let vl: usize = ret.len();
-						
-						if vl != 2 {
-							make_err!(argcn, i, vl, 2);
+
+					"-" => {
+						if ret.len() == 0 {
+							make_err!(argcf, i, 0, 1);
 						}
 						
-						let tok2: tokenizer::Token = ret.pop().unwrap();
-						let tok: tokenizer::Token = ret.pop().unwrap();
-						if let tokenizer::Token::Digit(d) = tok {
-							if let tokenizer::Token::Digit(d2) = tok2 {
-								return vec![tokenizer::Token::Digit((d < d2) as u128)];
-							} else {
-								make_err!(argt, i, tok2, 2);
-							}
+						let tok: tokenizer::Token = ret.remove(0);
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
+
+						for (index, t) in ret.into_iter().enumerate() {
+							if let Some(d) = big_of(&t) {
+								r = r.sub(&d);
+								continue
+							}
+							
+							make_err!(argt, i, t, index+2);
+						}
+
+						return vec![big_token(r)];
 					}
 
-					"+" => {
+					"*" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 						
 						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
 
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								 r += d;
-								 continue
+							if let Some(d) = big_of(&t) {
+								r = r.mul(&d);
+								continue
 							}
 							
-							make_err!(argt, i, t, index+1);
+							make_err!(argt, i, t, index+2);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
 
-					"-" => {
-						if ret.len() == 0 {
+					"wrapping-+"|"wrapping--"|"wrapping-*"|
+					"saturating-+"|"saturating--"|"saturating-*" => {
+						if ret.is_empty() {
 							make_err!(argcf, i, 0, 1);
 						}
-						
+
 						let tok: tokenizer::Token = ret.remove(0);
 						let mut r: u128;
 						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
 
 						for (index, t) in ret.into_iter().enumerate() {
 							if let tokenizer::Token::Digit(d) = t {
-								if d > r {
-									eprintln!("ERR: {r} - {d}: substract with overflow");
-									process::exit(69);
-								}
-								
-								r -= d;
+								r = match i.as_str() {
+									"wrapping-+" => r.wrapping_add(d),
+									"wrapping--" => r.wrapping_sub(d),
+									"wrapping-*" => r.wrapping_mul(d),
+									"saturating-+" => r.saturating_add(d),
+									"saturating--" => r.saturating_sub(d),
+									_ => r.saturating_mul(d),
+								};
+
 								continue
 							}
-							
-							make_err!(argt, i, t, index+1);
+
+							make_err!(argt, i, t, index+2);
 						}
 
 						return vec![tokenizer::Token::Digit(r)];
 					}
 
-					"*" => {
+					"wrap-u8"|"wrap-u16"|"wrap-u32"|"wrap-u64"|"wrap-i64" => {
+						if ret.is_empty() {
+							make_err!(argcf, i, 0, 1);
+						}
+
+						let mut tmp: Vec<tokenizer::Token> = Vec::new();
+						mem::swap(&mut ret, &mut tmp);
+
+						for (index, t) in tmp.into_iter().enumerate() {
+							if let Some(d) = big_of(&t) {
+								let d: u128 = d.low_u128();
+								ret.push(big_token(match i.as_str() {
+									"wrap-u8" => bigint::BigInt::from_u128(d as u8 as u128),
+									"wrap-u16" => bigint::BigInt::from_u128(d as u16 as u128),
+									"wrap-u32" => bigint::BigInt::from_u128(d as u32 as u128),
+									"wrap-u64" => bigint::BigInt::from_u128(d as u64 as u128),
+									_ => bigint::BigInt::from_i128(d as u64 as i64 as i128),
+								}));
+
+								continue
+							}
+
+							make_err!(argt, i, t, index+1);
+						}
+
+						return ret;
+					}
+
+					"/" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 						
 						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
 
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								 r *= d;
-								 continue
+							if let Some(d) = big_of(&t) {
+								if let Some((q, _)) = r.divrem(&d) {
+									r = q;
+								} else {
+									make_err!(zerodiv, i);
+								}
+
+								continue
 							}
 							
-							make_err!(argt, i, t, index+1);
+							make_err!(argt, i, t, index+2);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
 
-					"/" => {
-						if ret.len() == 0 {
+					"mod"|"rem"|"divmod" => {
+						let vl: usize = ret.len();
+						if vl != 2 {
+							make_err!(argcn, i, vl, 2);
+						}
+
+						let tok2: tokenizer::Token = ret.pop().unwrap();
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let Some(d) = big_of(&tok) {
+							if let Some(d2) = big_of(&tok2) {
+								let qr: Option<(bigint::BigInt, bigint::BigInt)> = if i == "mod" {
+									d.divmod(&d2)
+								} else {
+									d.divrem(&d2)
+								};
+
+								if let Some((q, r)) = qr {
+									if i == "divmod" {
+										return vec![big_token(q), big_token(r)];
+									}
+
+									return vec![big_token(r)];
+								}
+
+								make_err!(zerodiv, i);
+							} else {
+								make_err!(argt, i, tok2, 2);
+							}
+						} else {
+							make_err!(argt, i, tok, 1);
+						}
+					}
+
+					"gcd"|"lcm" => {
+						if ret.is_empty() {
 							make_err!(argcf, i, 0, 1);
 						}
 						
 						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
 
+						r = r.abs();
+
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								if d == 0 {
-									make_err!(zerodiv, i);
+							if let Some(d) = big_of(&t) {
+								if i == "gcd" {
+									r = r.gcd(&d);
+								} else if r.is_zero() || d.is_zero() {
+									r = bigint::BigInt::zero();
+								} else {
+									r = r.mul(&d).abs().divrem(&r.gcd(&d)).unwrap().0;
 								}
-								
-								r /= d;
+
 								continue
 							}
 							
-							make_err!(argt, i, t, index+1);
+							make_err!(argt, i, t, index+2);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
+					}
+
+					"abs"|"sign" => {
+						let vl: usize = ret.len();
+						if vl != 1 {
+							make_err!(argcn, i, vl, 1);
+						}
+
+						let tok: tokenizer::Token = ret.pop().unwrap();
+						if let Some(d) = big_of(&tok) {
+							if i == "abs" {
+								return vec![big_token(d.abs())];
+							}
+
+							return vec![big_token(d.signum())];
+						}
+
+						make_err!(argt, i, tok, 1);
+					}
+
+					"pow" => {
+						let vl: usize = ret.len();
+						if !(2..=3).contains(&vl) {
+							make_err!(argc, i, vl, 2, 3);
+						}
+
+						let m_tok: Option<tokenizer::Token> = if vl == 3 { ret.pop() } else { None };
+						let exp_tok: tokenizer::Token = ret.pop().unwrap();
+						let base_tok: tokenizer::Token = ret.pop().unwrap();
+
+						let base: bigint::BigInt;
+						if let Some(d) = big_of(&base_tok) {
+							base = d;
+						} else {
+							make_err!(argt, i, base_tok, 1);
+						}
+
+						let exp: bigint::BigInt = match big_of(&exp_tok) {
+							Some(d) if !d.is_neg() => d,
+							Some(_) => {
+								make_err!(value, i, exp_tok, "non-negative integer");
+							}
+
+							None => {
+								make_err!(argt, i, exp_tok, 2);
+							}
+						};
+
+						if let Some(t) = m_tok {
+							let m: bigint::BigInt;
+							if let Some(d) = big_of(&t) {
+								m = d;
+							} else {
+								make_err!(argt, i, t, 3);
+							}
+
+							if m.is_zero() {
+								make_err!(zerodiv, i);
+							}
+
+							let mut b: bigint::BigInt = base.divmod(&m).unwrap().1;
+							let mut r: bigint::BigInt = bigint::BigInt::from_u128(1).divmod(&m).unwrap().1;
+							for k in 0..exp.bits() {
+								if exp.bit(k) {
+									r = r.mul(&b).divmod(&m).unwrap().1;
+								}
+
+								b = b.mul(&b).divmod(&m).unwrap().1;
+							}
+
+							return vec![big_token(r)];
+						}
+
+						// 0, 1 and -1 stay small for any exponent //;
+						if base.bits() <= 1 {
+							let odd: bool = exp.bit(0);
+							return vec![big_token(if exp.is_zero() {
+								bigint::BigInt::from_u128(1)
+							} else if base.is_neg() && !odd {
+								base.neg()
+							} else {
+								base
+							})];
+						}
+
+						let bits: Option<usize> = exp.to_u128()
+							.filter(|&e| e <= usize::MAX as u128)
+							.map(|e| e as usize)
+							.and_then(|e| e.checked_mul(base.bits() - 1));
+
+						match bits {
+							Some(b) if b <= POW_MAX_BITS => {
+								return vec![big_token(base.pow(exp.to_u128().unwrap()))];
+							}
+
+							_ => {
+								raise!(flow, "`{}`: {} ^ {}: result is too big, limit is {} bits", i, base, exp, POW_MAX_BITS);
+							}
+						}
 					}
 
 					"&"|"bit-and" => {
@@ -1202,109 +4193,88 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 							make_err!(argcf, i, 0, 1);
 						}
 						
-						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
-						} else {
-							make_err!(argt, i, tok, 1);
-						}
-
+						let mut r: bigint::BigInt = bigint::BigInt::zero();
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								 r &= d;
-								 continue
+							if let Some(d) = big_of(&t) {
+								r = if index == 0 { d } else { r.bit_and(&d) };
+								continue
 							}
 							
 							make_err!(argt, i, t, index+1);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
-					
+
 					"|"|"bit-or" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 						
-						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
-						} else {
-							make_err!(argt, i, tok, 1);
-						}
-
+						let mut r: bigint::BigInt = bigint::BigInt::zero();
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								 r |= d;
-								 continue
+							if let Some(d) = big_of(&t) {
+								r = if index == 0 { d } else { r.bit_or(&d) };
+								continue
 							}
 							
 							make_err!(argt, i, t, index+1);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
-					
+
 					"^"|"bit-xor" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 						
-						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
-						} else {
-							make_err!(argt, i, tok, 1);
-						}
-
+						let mut r: bigint::BigInt = bigint::BigInt::zero();
 						for (index, t) in ret.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								r ^= d;
+							if let Some(d) = big_of(&t) {
+								r = if index == 0 { d } else { r.bit_xor(&d) };
 								continue
 							}
 							
 							make_err!(argt, i, t, index+1);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
-					
+
 					">>"|"bit-rshift" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 						
 						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
-							 r = d;
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
+							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
 
 						for (index, t) in ret.into_iter().enumerate() {
 							if let tokenizer::Token::Digit(d) = t {
-								 r >>= d;
-								 continue
+								r = r.shr(d.min(u32::MAX as u128) as usize);
+								continue
 							}
 							
-							make_err!(argt, i, t, index+1);
+							make_err!(argt, i, t, index+2);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
-					
+
 					"<<"|"bit-lshift" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
 						}
 						
 						let tok: tokenizer::Token = ret.remove(0);
-						let mut r: u128;
-						if let tokenizer::Token::Digit(d) = tok {
+						let mut r: bigint::BigInt;
+						if let Some(d) = big_of(&tok) {
 							r = d;
 						} else {
 							make_err!(argt, i, tok, 1);
@@ -1312,14 +4282,18 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 
 						for (index, t) in ret.into_iter().enumerate() {
 							if let tokenizer::Token::Digit(d) = t {
-								r <<= d;
+								if d > u32::MAX as u128 && !r.is_zero() {
+									raise!(flow, "`{}`: {} << {}: shift is too big", i, r, d);
+								}
+
+								r = r.shl(d.min(u32::MAX as u128) as usize);
 								continue
 							}
 							
-							make_err!(argt, i, t, index+1);
+							make_err!(argt, i, t, index+2);
 						}
 
-						return vec![tokenizer::Token::Digit(r)];
+						return vec![big_token(r)];
 					}
 
 					"~"|"bit-not" => {
@@ -1328,19 +4302,12 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 							make_err!(argcn, i, vl, 1);
 						}
 
-						let mut tmp: Vec<tokenizer::Token> = Vec::new();
-						mem::swap(&mut ret, &mut tmp);
-						
-						for (index, t) in tmp.into_iter().enumerate() {
-							if let tokenizer::Token::Digit(d) = t {
-								ret.push(tokenizer::Token::Digit(!d));
-								continue;
-							}
-
-							make_err!(argt, i, t, index+1);
+						let t: tokenizer::Token = ret.pop().unwrap();
+						if let Some(d) = big_of(&t) {
+							return vec![big_token(d.bit_not())];
 						}
 
-						return ret;
+						make_err!(argt, i, t, 1);
 					}
 								
 					"range"|".." => {
@@ -1378,12 +4345,15 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 								end = d0;
 							}
 								
-							ret.clear();	
-							for i in (start..end).step_by(step as usize) {
-								ret.push(tokenizer::Token::Digit(i));
+							if step == 0 {
+								make_err!(value, i, token, "step above 0");
 							}
 
-							return ret;
+							return vec![tokenizer::Token::Iter(iter::Lazy::new(iter::Source::Range {
+								next: start,
+								end: Some(end),
+								step,
+							}))];
 						}
 
 						make_err!(argt, i, token, 1);
@@ -1397,7 +4367,7 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 						
 						let tok: tokenizer::Token = ret.remove(0);
 						if let tokenizer::Token::Str(s) = tok {
-							return run_file(s.as_str(), 0, &ret, funcs, lambdas, vars);
+							return run_file(s.as_str(), 0, &ret, funcs, lambdas, vars, flow);
 						}
 
 						make_err!(argt, i, tok, 1);
@@ -1411,7 +4381,7 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 						for (index, t) in ret.into_iter().enumerate() {
 							match t {
 								tokenizer::Token::Str(s)|tokenizer::Token::Ident(s) => {
-									run_include(s.as_str(), funcs, lambdas, vars);	
+									run_include(s.as_str(), funcs, lambdas, vars, flow);	
 								}
 
 								_ => {
@@ -1432,7 +4402,7 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 
 						match token {
 							tokenizer::Token::Str(s) => {
-								return run_str(s.as_str(), 0, &ret, funcs, lambdas, vars);
+								return run_str(s.as_str(), 0, &ret, funcs, lambdas, vars, flow);
 							}
 
 							_ => {
@@ -1453,16 +4423,73 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 
 						let tok: tokenizer::Token = ret.pop().unwrap();
 						if let tokenizer::Token::Ident(s) = tok {
-							if !vars.contains_key(&s) {
-								make_err!(unknown_ident, s);
+							if let Some(val) = vars.get(&s) {
+								return val.clone();
 							}
 
-							return vars[&s].clone();
+							make_err!(unknown_ident, s);
 						} else {
 							make_err!(argt, i, tok, 1);
 						}
 					}
 
+					"throw" => {
+						let mut st: String = String::new();
+						for (index, t) in ret.into_iter().enumerate() {
+							if index > 0 {
+								st.push(' ');
+							}
+
+							match t {
+								tokenizer::Token::Str(s) => {
+									st += &s;
+								}
+
+								tokenizer::Token::Digit(d) => {
+									st += &format!("{d}");
+								}
+
+								_ => {
+									st += &tokenizer::token_show(&t);
+								}
+							}
+						}
+
+						raise!(flow, "{}", st);
+					}
+
+					"break" => {
+						if flow.loops == 0 {
+							make_err!(outside, i, "loop");
+						}
+
+						flow.signal = Signal::Break(ret);
+						return vec![];
+					}
+
+					"continue" => {
+						let vl: usize = ret.len();
+						if vl != 0 {
+							make_err!(argcn, i, vl, 0);
+						}
+
+						if flow.loops == 0 {
+							make_err!(outside, i, "loop");
+						}
+
+						flow.signal = Signal::Continue;
+						return vec![];
+					}
+
+					"return" => {
+						if flow.funcs == 0 {
+							make_err!(outside, i, "function");
+						}
+
+						flow.signal = Signal::Return(ret);
+						return vec![];
+					}
+
 					"%"|"arg" => {
 						if ret.len() == 0 {
 							make_err!(argcf, i, 0, 1);
@@ -1493,13 +4520,75 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 							make_err!(unknown_ident, i);
 						}
 
-						return run_tokens(&funcs.get(&i).unwrap().clone(), depth+1, 
-															&ret, funcs, lambdas, vars);
+						let f: func::Func = funcs.get(&i).unwrap().clone();
+						let loops: usize = mem::replace(&mut flow.loops, 0);
+						flow.funcs += 1;
+						vars.push_frame();
+
+						if let Some(sig) = f.sig {
+							let (slots, rest) = match sig.arrange(ret) {
+								Ok(v) => v,
+								Err(e) => {
+									make_err!(arity, i, e, sig.show);
+								}
+							};
+
+							// defaults are run in the new frame and see parameters before them //;
+							ret = Vec::new();
+							for (index, slot) in slots.into_iter().enumerate() {
+								match slot {
+									Some(t) => {
+										ret.push(t);
+									}
+
+									None => {
+										let code: Vec<tokenizer::Token> = sig.defaults[index].clone().unwrap();
+										let val: Vec<tokenizer::Token> =
+												run_tokens(&code, depth+1, &ret, funcs, lambdas, vars, flow);
+										let mut val: Vec<tokenizer::Token> = settle(val, depth, funcs, lambdas, vars, flow);
+
+										if flow.interrupted() || val.len() != 1 {
+											vars.pop_frame();
+											flow.funcs -= 1;
+											flow.loops = loops;
+											if !flow.interrupted() {
+												raise!(flow, "`{}`: default of parameter {} gives {} values instead of one", i, index+1, val.len());
+											}
+
+											return vec![];
+										}
+
+										ret.push(val.remove(0));
+									}
+								}
+							}
+
+							ret.extend(rest);
+						}
+
+						let mut res: Vec<tokenizer::Token> =
+								run_tokens(&f.code, depth+1,
+													 &ret, funcs, lambdas, vars, flow);
+						vars.pop_frame();
+						flow.funcs -= 1;
+						flow.loops = loops;
+
+						if let Signal::Return(_) = flow.signal {
+							if let Signal::Return(v) = mem::replace(&mut flow.signal, Signal::Normal) {
+								res = v;
+							}
+						}
+
+						return res;
 					}
 				}
 			}
 
-			tokenizer::Token::Str(_)|tokenizer::Token::Digit(_) => {
+			tokenizer::Token::Str(_)|tokenizer::Token::Digit(_)|
+			tokenizer::Token::Nil|tokenizer::Token::Bool(_)|
+			tokenizer::Token::Big(_)|tokenizer::Token::Iter(_)|
+			tokenizer::Token::Record(_)|tokenizer::Token::Vector(_)|
+			tokenizer::Token::Set(_) => {
 				ret.insert(0, fun);
 				return ret;
 			}
@@ -1516,38 +4605,56 @@ pub fn run_tokens(tokens: &Vec<tokenizer::Token>,
 pub fn run_str(s: &str,
 							 depth: usize,
 							 args: &Vec<tokenizer::Token>,
-							 funcs: &mut HashMap<String, Vec<tokenizer::Token>>,
+							 funcs: &mut HashMap<String, func::Func>,
 							 lambdas: &mut usize,
-							 vars: &mut HashMap<String, Vec<tokenizer::Token>>
+							 vars: &mut vars::Vars,
+							 flow: &mut Flow
 ) -> Vec<tokenizer::Token> {
 	let tokens: Vec<tokenizer::Token> = tokenizer::tokenize(s);
-	return run_tokens(&tokens, depth, args, funcs, lambdas, vars);
+	run_tokens(&tokens, depth, args, funcs, lambdas, vars, flow)
 }
 
 pub fn run_file(f: &str,
 								depth: usize,
 								args: &Vec<tokenizer::Token>,
-								funcs: &mut HashMap<String, Vec<tokenizer::Token>>,
+								funcs: &mut HashMap<String, func::Func>,
 								lambdas: &mut usize,
-								vars: &mut HashMap<String, Vec<tokenizer::Token>>
+								vars: &mut vars::Vars,
+								flow: &mut Flow
 ) -> Vec<tokenizer::Token> {
 	return run_str(
 		fs::read_to_string(f)
 			.expect("failed to open file")
-			.as_str(), depth, args, funcs, lambdas, vars);
+			.as_str(), depth, args, funcs, lambdas, vars, flow);
 }
 
 pub fn run_include(f: &str,
-									 funcs: &mut HashMap<String, Vec<tokenizer::Token>>,
+									 funcs: &mut HashMap<String, func::Func>,
 									 lambdas: &mut usize,
-									 vars: &mut HashMap<String, Vec<tokenizer::Token>>) -> () {
+									 vars: &mut vars::Vars,
+									 flow: &mut Flow) {
 	run_file(("/usr/include/jll/".to_owned()+f+".jll").as_str(), 0, &vec![],
-					 funcs, lambdas, vars);
+					 funcs, lambdas, vars, flow);
 }
 
 pub fn run_file_init(f: &str) -> Vec<tokenizer::Token> {
-	let mut funcs: HashMap<String, Vec<tokenizer::Token>> = HashMap::new();
-	let mut vars: HashMap<String, Vec<tokenizer::Token>> = HashMap::new();
+	let mut funcs: HashMap<String, func::Func> = HashMap::new();
+	let mut vars: vars::Vars = vars::Vars::new();
 	let mut lambdas: usize = 0;
-	return run_file(f, 0, &vec![], &mut funcs, &mut lambdas, &mut vars);
+	let mut flow: Flow = Flow {
+		signal: Signal::Normal,
+		loops: 0,
+		funcs: 0,
+		gen: None,
+	};
+
+	let ret: Vec<tokenizer::Token> =
+			run_file(f, 0, &vec![], &mut funcs, &mut lambdas, &mut vars, &mut flow);
+
+	if let Signal::Error(msg) = flow.signal {
+		eprintln!("ERR: {}", msg);
+		process::exit(10);
+	}
+
+	ret
 }
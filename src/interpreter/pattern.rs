@@ -0,0 +1,224 @@
+use super::tokenizer::Token;
+
+// patterns of `match`
+//   _                       anything
+//   name                    anything, bound to name
+//   1, 'str', nil, true     equal value
+//   (list p... & rest)      sequence, head can be omitted if first pattern is literal
+//   (case p...)             record of type `case` with fields matching in order
+//   (case :field p...)      same, but fields are picked by name //;
+pub enum Pat {
+	Any,
+	Bind(Token),
+	Lit(Token),
+	List(Vec<Pat>, Option<Box<Pat>>),
+	Rec(String, Vec<(Option<String>, Pat)>),
+}
+
+// operand of form, keeping parens of nested forms //;
+pub enum Item {
+	Atom(Token),
+	Form(Vec<Token>),
+}
+
+pub fn items(tokens: &[Token]) -> Vec<Item> {
+	let mut res: Vec<Item> = Vec::new();
+	let mut stack: Vec<Token> = Vec::new();
+	let mut deep: u16 = 0;
+
+	for t in tokens.iter() {
+		match t {
+			Token::OPair => {
+				if deep > 0 {
+					stack.push(t.clone());
+				}
+
+				deep += 1;
+			}
+
+			Token::CPair => {
+				deep -= 1;
+				if deep > 0 {
+					stack.push(t.clone());
+				} else {
+					res.push(Item::Form(stack.clone()));
+					stack.clear();
+				}
+			}
+
+			_ if deep > 0 => {
+				stack.push(t.clone());
+			}
+
+			_ => {
+				res.push(Item::Atom(t.clone()));
+			}
+		}
+	}
+
+	res
+}
+
+fn is_ident(it: &Item, name: &str) -> bool {
+	match it {
+		Item::Atom(Token::Ident(s)) => s == name,
+		_ => false,
+	}
+}
+
+pub fn parse(it: &Item) -> Result<Pat, String> {
+	match it {
+		Item::Atom(Token::Ident(s)) if s == "_" => Ok(Pat::Any),
+		Item::Atom(t @ Token::Ident(_)) => Ok(Pat::Bind(t.clone())),
+		Item::Atom(t) => Ok(Pat::Lit(t.clone())),
+		Item::Form(v) => {
+			let mut subs: Vec<Item> = items(v);
+			if subs.is_empty() {
+				return Ok(Pat::List(Vec::new(), None));
+			}
+
+			let head: Option<String> = match subs[0] {
+				Item::Atom(Token::Ident(ref s)) => Some(s.clone()),
+				_ => None,
+			};
+
+			match head {
+				Some(ref s) if s == "list" => {
+					subs.remove(0);
+					parse_list(&subs)
+				}
+
+				Some(name) => {
+					subs.remove(0);
+					let mut fields: Vec<(Option<String>, Pat)> = Vec::new();
+					let mut rest = subs.iter();
+					while let Some(sub) = rest.next() {
+						let key: Option<String> = match sub {
+							Item::Atom(Token::Ident(s)) if s.starts_with(':') && s.len() > 1 => Some(s[1..].to_string()),
+							_ => None,
+						};
+
+						match key {
+							Some(k) => match rest.next() {
+								Some(p) => fields.push((Some(k), parse(p)?)),
+								None => return Err(format!("pattern expected after `:{}`", k)),
+							},
+							None => fields.push((None, parse(sub)?)),
+						}
+					}
+
+					Ok(Pat::Rec(name, fields))
+				}
+
+				None => parse_list(&subs),
+			}
+		}
+	}
+}
+
+fn parse_list(subs: &[Item]) -> Result<Pat, String> {
+	let mut pats: Vec<Pat> = Vec::new();
+	for (index, sub) in subs.iter().enumerate() {
+		if is_ident(sub, "&") {
+			if index + 2 != subs.len() {
+				return Err(String::from("single pattern expected after `&`"));
+			}
+
+			return Ok(Pat::List(pats, Some(Box::new(parse(&subs[index+1])?))));
+		}
+
+		pats.push(parse(sub)?);
+	}
+
+	Ok(Pat::List(pats, None))
+}
+
+// bindings are pairs of name and values //;
+pub fn match_seq(p: &Pat, vals: &[Token], binds: &mut Vec<(Token, Vec<Token>)>) -> bool {
+	match p {
+		Pat::List(pats, rest) => {
+			if vals.len() < pats.len() || (rest.is_none() && vals.len() != pats.len()) {
+				return false;
+			}
+
+			for (sub, v) in pats.iter().zip(vals.iter()) {
+				if !match_one(sub, v, binds) {
+					return false;
+				}
+			}
+
+			match rest {
+				Some(r) => match **r {
+					Pat::Bind(ref n) => {
+						binds.push((n.clone(), vals[pats.len()..].to_vec()));
+						true
+					}
+
+					_ => match_seq(r, &vals[pats.len()..], binds),
+				},
+				None => true,
+			}
+		}
+
+		Pat::Any => true,
+		Pat::Bind(n) => {
+			binds.push((n.clone(), vals.to_vec()));
+			true
+		}
+
+		_ => vals.len() == 1 && match_one(p, &vals[0], binds),
+	}
+}
+
+fn match_one(p: &Pat, t: &Token, binds: &mut Vec<(Token, Vec<Token>)>) -> bool {
+	match p {
+		Pat::Any => true,
+		Pat::Bind(n) => {
+			binds.push((n.clone(), vec![t.clone()]));
+			true
+		}
+
+		Pat::Lit(l) => l == t,
+		Pat::List(..) => false,
+		Pat::Rec(name, fields) => {
+			let r = match t {
+				Token::Record(r) if r.name == *name => r,
+				_ => return false,
+			};
+
+			let positional: usize = fields.iter().filter(|f| f.0.is_none()).count();
+			if positional > 0 && positional != r.fields.len() {
+				return false;
+			}
+
+			let mut index: usize = 0;
+			for (key, sub) in fields.iter() {
+				let v: &Token = match key {
+					Some(k) => match r.get(k) {
+						Some(v) => v,
+						None => return false,
+					},
+					None => {
+						index += 1;
+						&r.fields[index-1].1
+					}
+				};
+
+				if !match_one(sub, v, binds) {
+					return false;
+				}
+			}
+
+			true
+		}
+	}
+}
+
+// case names covered by pattern as a whole, None if it matches anything //;
+pub fn covers(p: &Pat) -> Option<Vec<String>> {
+	match p {
+		Pat::Any|Pat::Bind(_) => None,
+		Pat::Rec(name, _) => Some(vec![name.clone()]),
+		_ => Some(Vec::new()),
+	}
+}
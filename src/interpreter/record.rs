@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use super::tokenizer::Token;
+
+// value of type made by `defstruct`
+// fields keep order of definition, records are compared by type and values //;
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Record {
+	pub name: String,
+	pub fields: Vec<(String, Token)>,
+	pub variant: Option<Arc<Variant>>,
+}
+
+// type made by `defvariant`, every case of it is record type //;
+#[derive(PartialEq, Eq, Hash)]
+pub struct Variant {
+	pub name: String,
+	pub cases: Vec<String>,
+}
+
+impl Record {
+	pub fn get(&self, field: &str) -> Option<&Token> {
+		self.fields.iter().find(|f| f.0 == field).map(|f| &f.1)
+	}
+
+	// point{x: 1, y: 2}, strings are quoted //;
+	pub fn show(&self) -> String {
+		let fields: Vec<String> = self.fields.iter()
+			.map(|(n, v)| format!("{}: {}", n, show_value(v)))
+			.collect();
+
+		format!("{}{{{}}}", self.name, fields.join(", "))
+	}
+}
+
+// value inside of record or vector //;
+pub fn show_value(t: &Token) -> String {
+	match t {
+		Token::Str(s) => format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'")),
+		Token::Digit(d) => format!("{}", d),
+		Token::Ident(i) => i.clone(),
+		Token::Nil => String::from("nil"),
+		Token::Bool(b) => format!("{}", b),
+		Token::Big(b) => format!("{}", b),
+		Token::Record(r) => r.show(),
+		Token::Vector(v) => v.show(),
+		Token::Set(s) => s.show(),
+		Token::Iter(_) => String::from("<iter>"),
+		Token::OPair => String::from("("),
+		Token::CPair => String::from(")"),
+	}
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use super::record;
+use super::tokenizer::Token;
+
+// hash set of values
+// elements remember order of insertion, so sets are printed and turned
+//   into lists the same way every run
+// changing set copies it only if it's also held somewhere else //;
+#[derive(Clone)]
+pub struct Set {
+	items: Arc<HashMap<Token, u64>>,
+	next: u64,
+}
+
+impl Set {
+	pub fn new(items: Vec<Token>) -> Set {
+		let mut s: Set = Set {
+			items: Arc::new(HashMap::new()),
+			next: 0,
+		};
+
+		for t in items.into_iter() {
+			s.insert(t);
+		}
+
+		s
+	}
+
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	pub fn contains(&self, t: &Token) -> bool {
+		self.items.contains_key(t)
+	}
+
+	pub fn insert(&mut self, t: Token) {
+		if self.contains(&t) {
+			return;
+		}
+
+		Arc::make_mut(&mut self.items).insert(t, self.next);
+		self.next += 1;
+	}
+
+	pub fn remove(&mut self, t: &Token) {
+		if self.contains(t) {
+			Arc::make_mut(&mut self.items).remove(t);
+		}
+	}
+
+	pub fn is_subset(&self, o: &Set) -> bool {
+		self.len() <= o.len() && self.items.keys().all(|t| o.contains(t))
+	}
+
+	pub fn union(&self, o: &Set) -> Set {
+		let mut res: Set = self.clone();
+		for t in o.items().into_iter() {
+			res.insert(t);
+		}
+
+		res
+	}
+
+	pub fn intersection(&self, o: &Set) -> Set {
+		Set::new(self.items().into_iter().filter(|t| o.contains(t)).collect())
+	}
+
+	pub fn difference(&self, o: &Set) -> Set {
+		Set::new(self.items().into_iter().filter(|t| !o.contains(t)).collect())
+	}
+
+	// in order of insertion //;
+	pub fn items(&self) -> Vec<Token> {
+		let mut res: Vec<(&Token, &u64)> = self.items.iter().collect();
+		res.sort_by_key(|p| *p.1);
+		res.into_iter().map(|p| p.0.clone()).collect()
+	}
+
+	// #{1, 'a'}, strings are quoted //;
+	pub fn show(&self) -> String {
+		let items: Vec<String> = self.items().iter().map(record::show_value).collect();
+		format!("#{{{}}}", items.join(", "))
+	}
+}
+
+impl PartialEq for Set {
+	fn eq(&self, o: &Set) -> bool {
+		self.len() == o.len() && self.is_subset(o)
+	}
+}
+
+impl Eq for Set {}
+
+// doesn't depend on order, as equal sets may have different one //;
+impl Hash for Set {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		let mut sum: u64 = 0;
+		for t in self.items.keys() {
+			let mut h: DefaultHasher = DefaultHasher::new();
+			t.hash(&mut h);
+			sum = sum.wrapping_add(h.finish());
+		}
+
+		sum.hash(state);
+	}
+}
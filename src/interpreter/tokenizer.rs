@@ -1,3 +1,10 @@
+use super::bigint;
+use super::iter;
+use super::record;
+use super::set;
+use super::vector;
+use std::sync::Arc;
+
 #[derive(PartialEq)]
 #[derive(Eq)]
 #[derive(Clone)]
@@ -8,12 +15,20 @@ pub enum Token {
 	CPair,
 	Digit(u128),
 	Ident(String),
+	Nil,
+	Bool(bool),
+	Big(bigint::BigInt),
+	Iter(iter::Lazy),
+	Record(Arc<record::Record>),
+	Vector(vector::Vector),
+	Set(set::Set),
 }
 
 #[derive(PartialEq)]
 pub enum TokenKind {
 	Nil,
 	Str,
+	Char,
 	OPair,
 	CPair,
 	Digit,
@@ -24,6 +39,8 @@ enum TokenizerState {
 	Common,
 	InString,
 	InStringEscape,
+	InStringHex,
+	InStringUnicode,
 	InComment,
 }
 
@@ -31,6 +48,7 @@ pub fn token_kind(k: &TokenKind) -> &'static str {
 	match *k {
 		TokenKind::Nil => "Nil",
 		TokenKind::Str => "Str",
+		TokenKind::Char => "Char",
 		TokenKind::OPair => "OPair",
 		TokenKind::CPair => "CPair",
 		TokenKind::Digit => "Digit",
@@ -45,6 +63,13 @@ pub fn token_show(t: &Token) -> String {
 		Token::CPair => String::from("CPair"),
 		Token::Digit(d) => format!("Digit({d})"),
 		Token::Ident(i) => format!("Ident('{i}')"),
+		Token::Nil => String::from("Nil"),
+		Token::Bool(b) => format!("Bool({b})"),
+		Token::Big(b) => format!("Big({b})"),
+		Token::Iter(_) => String::from("Iter"),
+		Token::Record(r) => format!("Record({})", r.show()),
+		Token::Vector(v) => format!("Vector({})", v.show()),
+		Token::Set(s) => format!("Set({})", s.show()),
 	}
 }
 
@@ -55,6 +80,161 @@ pub fn print_tokens(v: &Vec<Token>) -> () {
 	}
 } */
 
+// strips first and last blank lines and common indentation of triple-quoted string //;
+fn dedent(s: &str) -> String {
+	let mut lines: Vec<&str> = s.split('\n').collect();
+	if lines.len() > 1 && lines[0].trim().is_empty() {
+		lines.remove(0);
+	}
+
+	if lines.len() > 1 && lines[lines.len()-1].trim().is_empty() {
+		lines.pop();
+	}
+
+	let indent_of = |l: &str| l.len() - l.trim_start_matches([' ', '\t']).len();
+	let indent: usize = lines.iter()
+		.filter(|l| !l.trim().is_empty())
+		.map(|l| indent_of(l))
+		.min()
+		.unwrap_or(0);
+
+	let stripped: Vec<&str> = lines.iter()
+		.map(|l| &l[indent.min(indent_of(l))..])
+		.collect();
+
+	stripped.join("\n")
+}
+
+// f'...' string, expressions in braces become arguments of `format` //;
+fn interpolate(s: &str) -> Option<Vec<Token>> {
+	let cs: Vec<char> = s.chars().collect();
+	let mut fmt: String = String::new();
+	let mut args: Vec<Token> = Vec::new();
+	let mut count: usize = 0;
+	let mut pos: usize = 0;
+
+	while pos < cs.len() {
+		let c: char = cs[pos];
+		pos += 1;
+
+		if c != '{' || (pos < cs.len() && cs[pos] == '{') {
+			fmt.push(c);
+			if (c == '{' || c == '}') && pos < cs.len() && cs[pos] == c {
+				fmt.push(c);
+				pos += 1;
+			}
+
+			continue
+		}
+
+		let start: usize = pos;
+		let mut depth: usize = 0;
+		let mut quote: Option<char> = None;
+		let mut colon: Option<usize> = None;
+
+		while pos < cs.len() {
+			let ch: char = cs[pos];
+			match quote {
+				Some(q) if ch == q => {
+					quote = None;
+				}
+
+				Some(_) => {
+					// VOID //;
+				}
+
+				None => match ch {
+					'\''|'"' => {
+						quote = Some(ch);
+					}
+
+					'(' => {
+						depth += 1;
+					}
+
+					')' => {
+						depth = depth.saturating_sub(1);
+					}
+
+					':' if depth == 0 && colon.is_none() => {
+						colon = Some(pos);
+					}
+
+					'}' if depth == 0 => {
+						break
+					}
+
+					_ => {
+						// VOID //;
+					}
+				}
+			}
+
+			pos += 1;
+		}
+
+		if pos == cs.len() {
+			eprintln!("ERR: tokenizer: unclosed '{{' in f-string '{}'",
+								s);
+
+			return None;
+		}
+
+		let end: usize = colon.unwrap_or(pos);
+		let expr: String = cs[start..end].iter().collect();
+		let spec: String = cs[end..pos].iter().collect();
+		let expr: &str = expr.trim();
+		pos += 1;
+
+		if expr.is_empty() {
+			eprintln!("ERR: tokenizer: empty expression in f-string '{}'",
+								s);
+
+			return None;
+		}
+
+		// trailing space ends last token of expression
+		// `format-arg` makes single value of whatever expression gives,
+		//   so every placeholder takes exactly one argument //;
+		let toks: Vec<Token> = tokenize(&format!("{} ", expr));
+		if toks.is_empty() {
+			return None;
+		}
+
+		args.push(Token::OPair);
+		args.push(Token::Ident(String::from("format-arg")));
+		args.extend(toks);
+		args.push(Token::CPair);
+		fmt += &format!("{{{}{}}}", count, spec);
+		count += 1;
+	}
+
+	let mut res: Vec<Token> = vec![Token::OPair, Token::Ident(String::from("format")), Token::Str(fmt)];
+	res.extend(args);
+	res.push(Token::CPair);
+	Some(res)
+}
+
+// decimal, 0x hex, 0b binary and 0o octal literals //;
+fn parse_number(s: &str) -> Option<Token> {
+	let (radix, digits): (u32, &str) = match s.get(..2) {
+		Some("0x") => (16, &s[2..]),
+		Some("0b") => (2, &s[2..]),
+		Some("0o") => (8, &s[2..]),
+		_ => (10, s),
+	};
+
+	if let Ok(d) = u128::from_str_radix(digits, radix) {
+		return Some(Token::Digit(d));
+	}
+
+	if digits.starts_with('-') || digits.starts_with('+') {
+		return None;
+	}
+
+	bigint::BigInt::parse(digits, radix).map(Token::Big)
+}
+
 macro_rules! token_push_new {
 	($token:ident, $kind:ident, $tokens:ident, $deref:ident, $next_kind:path) => {
 		if $deref && $kind != TokenKind::Ident {
@@ -68,7 +248,34 @@ macro_rules! token_push_new {
 			}
 
 			TokenKind::Digit => {
-				$tokens.push(Token::Digit($token.parse::<u128>().unwrap()));
+				if let Some(t) = parse_number(&$token) {
+					$tokens.push(t);
+				} else {
+					eprintln!("ERR: tokenizer: invalid number literal {}",
+										$token);
+
+					return vec![];
+				}
+			}
+
+			TokenKind::Char => {
+				let mut cs = $token.chars();
+				if let (Some(c), None) = (cs.next(), cs.next()) {
+					$tokens.push(Token::Digit(c as u128));
+				} else {
+					eprintln!("ERR: tokenizer: char literal #'{}' should contain single char",
+										$token);
+
+					return vec![];
+				}
+			}
+
+			TokenKind::Ident if !$deref && $token == "nil" => {
+				$tokens.push(Token::Nil);
+			}
+
+			TokenKind::Ident if !$deref && ($token == "true" || $token == "false") => {
+				$tokens.push(Token::Bool($token == "true"));
 			}
 
 			TokenKind::Ident => {
@@ -111,8 +318,19 @@ pub fn tokenize(s: &str) -> Vec<Token> {
 	let mut quote_start: bool = false;
 	let mut quote_level: usize = 0;
 	let mut deref: bool = false;
+	let mut str_quote: char = '\'';
+	let mut str_raw: bool = false;
+	let mut str_triple: bool = false;
+	let mut str_fmt: bool = false;
+	let mut esc: String = String::new();
+	let chars: Vec<char> = s.chars().collect();
+	let mut pos: usize = 0;
+
+	while pos < chars.len() {
+		let c: char = chars[pos];
+		let triple_next: bool = pos + 2 < chars.len() &&
+				chars[pos+1] == c && chars[pos+2] == c;
 
-	for c in s.chars().into_iter() {
 		match state {
 			TokenizerState::InComment => {
 				if c == '\n' {
@@ -122,14 +340,32 @@ pub fn tokenize(s: &str) -> Vec<Token> {
 
 			TokenizerState::InString => {
 				match c {
-					'\'' => {
+					_ if c == str_quote && (!str_triple || triple_next) => {
 						state = TokenizerState::Common;
+						if str_triple {
+							pos += 2;
+						}
+
 						if !in_quote {
-							token_push_new!(tmp_st, kind, tokens, deref);
+							if str_triple {
+								tmp_st = dedent(&tmp_st);
+							}
+
+							if str_fmt {
+								match interpolate(&tmp_st) {
+									Some(v) => tokens.extend(v),
+									None => return vec![],
+								}
+
+								tmp_st = String::new();
+								kind = TokenKind::Nil;
+							} else {
+								token_push_new!(tmp_st, kind, tokens, deref);
+							}
 						}
 					}
 
-					'\\' => {
+					'\\' if !str_raw => {
 						state = TokenizerState::InStringEscape;
 					}
 
@@ -142,6 +378,7 @@ pub fn tokenize(s: &str) -> Vec<Token> {
 			}
 
 			TokenizerState::InStringEscape => {
+				state = TokenizerState::InString;
 				if !in_quote {
 					match c {
 						't' => {
@@ -152,21 +389,100 @@ pub fn tokenize(s: &str) -> Vec<Token> {
 							tmp_st.push('\n');
 						}
 
+						'r' => {
+							tmp_st.push('\r');
+						}
+
+						'0' => {
+							tmp_st.push('\0');
+						}
+
+						'x' => {
+							esc.clear();
+							state = TokenizerState::InStringHex;
+						}
+
+						'u' => {
+							esc.clear();
+							state = TokenizerState::InStringUnicode;
+						}
+
 						_ => {
 							tmp_st.push(c);
 						}
 					}
 				}
-				
-				state = TokenizerState::InString;
+			}
+
+			TokenizerState::InStringHex => {
+				if !c.is_ascii_hexdigit() {
+					eprintln!("ERR: tokenizer: invalid escape \\x{}{}",
+										esc, c);
+
+					return vec![];
+				}
+
+				esc.push(c);
+				if esc.len() == 2 {
+					tmp_st.push(u8::from_str_radix(&esc, 16).unwrap() as char);
+					state = TokenizerState::InString;
+				}
+			}
+
+			TokenizerState::InStringUnicode => {
+				if esc.is_empty() && c != '{' {
+					eprintln!("ERR: tokenizer: '{{' expected after \\u, {} found",
+										c);
+
+					return vec![];
+				}
+
+				if c == '}' {
+					let code: Option<char> = u32::from_str_radix(&esc[1..], 16).ok()
+						.and_then(std::char::from_u32);
+
+					if let Some(ch) = code {
+						tmp_st.push(ch);
+					} else {
+						eprintln!("ERR: tokenizer: invalid escape \\u{}}}",
+											esc);
+
+						return vec![];
+					}
+
+					state = TokenizerState::InString;
+				} else {
+					esc.push(c);
+				}
 			}
 
 			TokenizerState::Common => {
 				match c {
-					'\'' => {
+					'\''|'"' => {
 						state = TokenizerState::InString;
+						str_quote = c;
+						str_raw = false;
+						str_fmt = false;
+						str_triple = triple_next;
+						if str_triple {
+							pos += 2;
+						}
+
 						if !in_quote {
-							kind = TokenKind::Str;
+							if kind == TokenKind::Ident && tmp_st == "#" {
+								tmp_st.clear();
+								kind = TokenKind::Char;
+							} else if kind == TokenKind::Ident && tmp_st == "r" {
+								tmp_st.clear();
+								kind = TokenKind::Str;
+								str_raw = true;
+							} else if kind == TokenKind::Ident && tmp_st == "f" {
+								tmp_st.clear();
+								kind = TokenKind::Str;
+								str_fmt = true;
+							} else {
+								kind = TokenKind::Str;
+							}
 						}
 					}
 
@@ -280,6 +596,10 @@ pub fn tokenize(s: &str) -> Vec<Token> {
 									// VOID //;
 								}
 
+								TokenKind::Digit if c.is_ascii_alphabetic() => {
+									tmp_st.push(c);
+								}
+
 								_ => {
 									eprintln!("ERR: tokenizer: uncompleted token {} before ident",
 														token_kind(&kind));
@@ -301,10 +621,13 @@ pub fn tokenize(s: &str) -> Vec<Token> {
 				}
 			}
 		}
+
+		pos += 1;
 	}
 
 	match state {
-		TokenizerState::InString|TokenizerState::InStringEscape => {
+		TokenizerState::InString|TokenizerState::InStringEscape|
+		TokenizerState::InStringHex|TokenizerState::InStringUnicode => {
 			eprintln!("ERR: tokenizer: unterminated string '{}'",
 								tmp_st);
 
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use super::tokenizer;
+
+type Scope = HashMap<String, Vec<tokenizer::Token>>;
+
+// variables made by `mut`
+// every function call gets its own frame, so functions can't see
+//   locals of their callers
+// `bind` and loop bodies add scopes to the current frame
+// `mut` outside of any scope defines global variable //;
+pub struct Vars {
+	pub globals: Scope,
+	frames: Vec<Vec<Scope>>,
+}
+
+impl Vars {
+	pub fn new() -> Vars {
+		Vars {
+			globals: HashMap::new(),
+			frames: vec![Vec::new()],
+		}
+	}
+
+	pub fn push_frame(&mut self) {
+		self.frames.push(vec![HashMap::new()]);
+	}
+
+	pub fn pop_frame(&mut self) {
+		self.frames.pop();
+	}
+
+	pub fn push_scope(&mut self) {
+		self.frames.last_mut().unwrap().push(HashMap::new());
+	}
+
+	pub fn pop_scope(&mut self) {
+		self.frames.last_mut().unwrap().pop();
+	}
+
+	pub fn get(&self, name: &str) -> Option<&Vec<tokenizer::Token>> {
+		for scope in self.frames.last().unwrap().iter().rev() {
+			if let Some(val) = scope.get(name) {
+				return Some(val);
+			}
+		}
+
+		self.globals.get(name)
+	}
+
+	pub fn contains(&self, name: &str) -> bool {
+		self.get(name).is_some()
+	}
+
+	// returns false if name is already defined in the innermost scope //;
+	pub fn define(&mut self, name: String, val: Vec<tokenizer::Token>) -> bool {
+		let scope: &mut Scope = match self.frames.last_mut().unwrap().last_mut() {
+			Some(scope) => scope,
+			None => &mut self.globals,
+		};
+
+		if scope.contains_key(&name) {
+			return false;
+		}
+
+		scope.insert(name, val);
+		true
+	}
+
+	// returns false if there is no such variable //;
+	pub fn set(&mut self, name: String, val: Vec<tokenizer::Token>) -> bool {
+		for scope in self.frames.last_mut().unwrap().iter_mut().rev() {
+			if let Some(old) = scope.get_mut(&name) {
+				*old = val;
+				return true;
+			}
+		}
+
+		self.set_global(name, val)
+	}
+
+	pub fn define_global(&mut self, name: String, val: Vec<tokenizer::Token>) -> bool {
+		if self.globals.contains_key(&name) {
+			return false;
+		}
+
+		self.globals.insert(name, val);
+		true
+	}
+
+	pub fn set_global(&mut self, name: String, val: Vec<tokenizer::Token>) -> bool {
+		if let Some(old) = self.globals.get_mut(&name) {
+			*old = val;
+			return true;
+		}
+
+		false
+	}
+}
@@ -0,0 +1,262 @@
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use super::record;
+use super::tokenizer::Token;
+
+// persistent vector
+// balanced tree with elements in order of traversal, every change copies
+//   only the path to changed node, so old vector stays valid and shares
+//   the rest of the tree with new one
+// get, set, push and pop at both ends, split and concat are O(log n) //;
+#[derive(Clone)]
+pub struct Vector {
+	root: Tree,
+}
+
+type Tree = Option<Arc<Node>>;
+
+struct Node {
+	left: Tree,
+	val: Token,
+	right: Tree,
+	size: usize,
+	height: u8,
+}
+
+fn size(t: &Tree) -> usize {
+	t.as_ref().map_or(0, |n| n.size)
+}
+
+fn height(t: &Tree) -> u8 {
+	t.as_ref().map_or(0, |n| n.height)
+}
+
+fn node(left: Tree, val: Token, right: Tree) -> Tree {
+	Some(Arc::new(Node {
+		size: size(&left) + size(&right) + 1,
+		height: height(&left).max(height(&right)) + 1,
+		left,
+		val,
+		right,
+	}))
+}
+
+// parts of non-empty tree //;
+fn expose(t: &Tree) -> (Tree, Token, Tree) {
+	let n: &Arc<Node> = t.as_ref().unwrap();
+	(n.left.clone(), n.val.clone(), n.right.clone())
+}
+
+fn rotate_left(t: Tree) -> Tree {
+	let (l, v, r) = expose(&t);
+	let (rl, rv, rr) = expose(&r);
+	node(node(l, v, rl), rv, rr)
+}
+
+fn rotate_right(t: Tree) -> Tree {
+	let (l, v, r) = expose(&t);
+	let (ll, lv, lr) = expose(&l);
+	node(ll, lv, node(lr, v, r))
+}
+
+// tree of left, val and right, when left is much higher than right //;
+fn join_right(left: Tree, val: Token, right: Tree) -> Tree {
+	let (l, v, c) = expose(&left);
+	if height(&c) <= height(&right) + 1 {
+		let t: Tree = node(c, val, right);
+		if height(&t) <= height(&l) + 1 {
+			return node(l, v, t);
+		}
+
+		return rotate_left(node(l, v, rotate_right(t)));
+	}
+
+	let t: Tree = join_right(c, val, right);
+	let res: Tree = node(l.clone(), v, t.clone());
+	if height(&t) <= height(&l) + 1 {
+		return res;
+	}
+
+	rotate_left(res)
+}
+
+fn join_left(left: Tree, val: Token, right: Tree) -> Tree {
+	let (c, v, r) = expose(&right);
+	if height(&c) <= height(&left) + 1 {
+		let t: Tree = node(left, val, c);
+		if height(&t) <= height(&r) + 1 {
+			return node(t, v, r);
+		}
+
+		return rotate_right(node(rotate_left(t), v, r));
+	}
+
+	let t: Tree = join_left(left, val, c);
+	let res: Tree = node(t.clone(), v, r.clone());
+	if height(&t) <= height(&r) + 1 {
+		return res;
+	}
+
+	rotate_right(res)
+}
+
+fn join(left: Tree, val: Token, right: Tree) -> Tree {
+	if height(&left) > height(&right) + 1 {
+		return join_right(left, val, right);
+	}
+
+	if height(&right) > height(&left) + 1 {
+		return join_left(left, val, right);
+	}
+
+	node(left, val, right)
+}
+
+// first `at` elements and the rest //;
+fn split(t: &Tree, at: usize) -> (Tree, Tree) {
+	if t.is_none() {
+		return (None, None);
+	}
+
+	let (l, v, r) = expose(t);
+	let ls: usize = size(&l);
+	if at <= ls {
+		let (ll, lr) = split(&l, at);
+		return (ll, join(lr, v, r));
+	}
+
+	let (rl, rr) = split(&r, at - ls - 1);
+	(join(l, v, rl), rr)
+}
+
+fn split_last(t: &Tree) -> (Tree, Token) {
+	let (l, v, r) = expose(t);
+	if r.is_none() {
+		return (l, v);
+	}
+
+	let (rest, last) = split_last(&r);
+	(join(l, v, rest), last)
+}
+
+fn concat(left: Tree, right: Tree) -> Tree {
+	if left.is_none() {
+		return right;
+	}
+
+	let (l, last) = split_last(&left);
+	join(l, last, right)
+}
+
+fn build(items: &[Token]) -> Tree {
+	if items.is_empty() {
+		return None;
+	}
+
+	let mid: usize = items.len() / 2;
+	node(build(&items[..mid]), items[mid].clone(), build(&items[mid+1..]))
+}
+
+fn set(t: &Tree, index: usize, val: Token) -> Tree {
+	let (l, v, r) = expose(t);
+	let ls: usize = size(&l);
+	if index < ls {
+		return node(set(&l, index, val), v, r);
+	}
+
+	if index == ls {
+		return node(l, val, r);
+	}
+
+	node(l, v, set(&r, index - ls - 1, val))
+}
+
+fn collect(t: &Tree, res: &mut Vec<Token>) {
+	if let Some(n) = t {
+		collect(&n.left, res);
+		res.push(n.val.clone());
+		collect(&n.right, res);
+	}
+}
+
+impl Vector {
+	pub fn new(items: &[Token]) -> Vector {
+		Vector { root: build(items) }
+	}
+
+	pub fn len(&self) -> usize {
+		size(&self.root)
+	}
+
+	pub fn get(&self, index: usize) -> Option<&Token> {
+		let mut t: &Tree = &self.root;
+		let mut index: usize = index;
+		while let Some(n) = t {
+			let ls: usize = size(&n.left);
+			if index < ls {
+				t = &n.left;
+			} else if index == ls {
+				return Some(&n.val);
+			} else {
+				index -= ls + 1;
+				t = &n.right;
+			}
+		}
+
+		None
+	}
+
+	// None if index is out of bounds //;
+	pub fn set(&self, index: usize, val: Token) -> Option<Vector> {
+		if index >= self.len() {
+			return None;
+		}
+
+		Some(Vector { root: set(&self.root, index, val) })
+	}
+
+	pub fn push(&self, val: Token) -> Vector {
+		Vector { root: join(self.root.clone(), val, None) }
+	}
+
+	pub fn push_front(&self, val: Token) -> Vector {
+		Vector { root: join(None, val, self.root.clone()) }
+	}
+
+	// elements from..to, to is not included //;
+	pub fn slice(&self, from: usize, to: usize) -> Vector {
+		let (head, _) = split(&self.root, to);
+		let (_, tail) = split(&head, from);
+		Vector { root: tail }
+	}
+
+	pub fn concat(&self, other: &Vector) -> Vector {
+		Vector { root: concat(self.root.clone(), other.root.clone()) }
+	}
+
+	// [1, 'a'], strings are quoted //;
+	pub fn show(&self) -> String {
+		let items: Vec<String> = self.items().iter().map(record::show_value).collect();
+		format!("[{}]", items.join(", "))
+	}
+
+	pub fn items(&self) -> Vec<Token> {
+		let mut res: Vec<Token> = Vec::with_capacity(self.len());
+		collect(&self.root, &mut res);
+		res
+	}
+}
+
+impl PartialEq for Vector {
+	fn eq(&self, o: &Vector) -> bool {
+		self.len() == o.len() && self.items() == o.items()
+	}
+}
+
+impl Eq for Vector {}
+
+impl Hash for Vector {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.items().hash(state);
+	}
+}